@@ -0,0 +1,265 @@
+//! Rigging and preset animations.
+//!
+//! [`TripoClient::check_rig`] reports whether a model can be rigged, as a typed
+//! [`RigCheckResult`]. [`TripoClient::animate_model`] takes a model all the way from
+//! the rig check to an animated asset.
+//!
+//! The API identifies preset animations by strings such as `"preset:walk"`. The
+//! [`Animation`] enum mirrors the published catalog, so UIs can offer the valid choices
+//! from [`Animation::ALL`] instead of hardcoding strings.
+//!
+//! [`TripoClient::retarget_animations`] applies several animations to one rigged model
+//! and groups the resulting clips under a [`RetargetHandle`].
+
+use crate::client::TripoClient;
+use crate::error::TripoError;
+use crate::types::{RigFormat, TaskStatus};
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A preset animation that can be retargeted onto a rigged model.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Animation {
+    /// Standing idle.
+    #[serde(rename = "preset:idle")]
+    Idle,
+    /// A walk cycle.
+    #[serde(rename = "preset:walk")]
+    Walk,
+    /// A run cycle.
+    #[serde(rename = "preset:run")]
+    Run,
+    /// Climbing.
+    #[serde(rename = "preset:climb")]
+    Climb,
+    /// A jump.
+    #[serde(rename = "preset:jump")]
+    Jump,
+    /// A melee slash.
+    #[serde(rename = "preset:slash")]
+    Slash,
+    /// Shooting.
+    #[serde(rename = "preset:shoot")]
+    Shoot,
+    /// Reacting to a hit.
+    #[serde(rename = "preset:hurt")]
+    Hurt,
+    /// Falling.
+    #[serde(rename = "preset:fall")]
+    Fall,
+    /// Turning around.
+    #[serde(rename = "preset:turn")]
+    Turn,
+    /// A walk cycle for four-legged models.
+    #[serde(rename = "preset:quadruped:walk")]
+    QuadrupedWalk,
+    /// A walk cycle for six-legged models.
+    #[serde(rename = "preset:hexapod:walk")]
+    HexapodWalk,
+    /// A walk cycle for eight-legged models.
+    #[serde(rename = "preset:octopod:walk")]
+    OctopodWalk,
+    /// Forward movement for snake-like models.
+    #[serde(rename = "preset:serpentine:march")]
+    SerpentineMarch,
+    /// Swimming for fish-like models.
+    #[serde(rename = "preset:aquatic:march")]
+    AquaticMarch,
+}
+
+impl Animation {
+    /// Every preset animation, in catalog order.
+    pub const ALL: &'static [Animation] = &[
+        Animation::Idle,
+        Animation::Walk,
+        Animation::Run,
+        Animation::Climb,
+        Animation::Jump,
+        Animation::Slash,
+        Animation::Shoot,
+        Animation::Hurt,
+        Animation::Fall,
+        Animation::Turn,
+        Animation::QuadrupedWalk,
+        Animation::HexapodWalk,
+        Animation::OctopodWalk,
+        Animation::SerpentineMarch,
+        Animation::AquaticMarch,
+    ];
+
+    /// The animation's name as the API spells it, e.g. `"preset:walk"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Animation::Idle => "preset:idle",
+            Animation::Walk => "preset:walk",
+            Animation::Run => "preset:run",
+            Animation::Climb => "preset:climb",
+            Animation::Jump => "preset:jump",
+            Animation::Slash => "preset:slash",
+            Animation::Shoot => "preset:shoot",
+            Animation::Hurt => "preset:hurt",
+            Animation::Fall => "preset:fall",
+            Animation::Turn => "preset:turn",
+            Animation::QuadrupedWalk => "preset:quadruped:walk",
+            Animation::HexapodWalk => "preset:hexapod:walk",
+            Animation::OctopodWalk => "preset:octopod:walk",
+            Animation::SerpentineMarch => "preset:serpentine:march",
+            Animation::AquaticMarch => "preset:aquatic:march",
+        }
+    }
+
+    /// A file-system-safe name for the animation, e.g. `"quadruped_walk"`.
+    fn dir_name(self) -> String {
+        self.name().trim_start_matches("preset:").replace(':', "_")
+    }
+
+    /// Looks up an animation by its API name, e.g. `"preset:walk"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|animation| animation.name() == name)
+    }
+}
+
+/// The skeleton topology of a model, as detected by a rig check.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum RigType {
+    /// Two legs, like a human.
+    Biped,
+    /// Four legs.
+    Quadruped,
+    /// Six legs.
+    Hexapod,
+    /// Eight legs.
+    Octopod,
+    /// Winged.
+    Avian,
+    /// Snake-like, without legs.
+    Serpentine,
+    /// Fish-like.
+    Aquatic,
+    /// A topology without a dedicated skeleton.
+    Others,
+    /// A topology not known to this crate version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The outcome of a rig check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RigCheckResult {
+    /// Whether the model can be rigged.
+    pub riggable: bool,
+    /// The detected skeleton topology, if reported.
+    pub topology: Option<RigType>,
+    /// Why the model cannot be rigged, if reported.
+    pub reasons: Vec<String>,
+}
+
+/// Options for [`TripoClient::animate_model`].
+#[derive(Debug, Clone)]
+pub struct AnimateOptions {
+    /// The file format of the rigged and animated models. The server default is used
+    /// if `None`.
+    pub format: Option<RigFormat>,
+    /// Whether to check that the model can be rigged before rigging it. Defaults to
+    /// `true`.
+    pub check_rig: bool,
+}
+
+impl Default for AnimateOptions {
+    fn default() -> Self {
+        Self {
+            format: None,
+            check_rig: true,
+        }
+    }
+}
+
+impl AnimateOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the file format of the rigged and animated models.
+    pub fn format(mut self, format: RigFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Skips the rig check, e.g. for models already known to be riggable.
+    pub fn check_rig(mut self, check_rig: bool) -> Self {
+        self.check_rig = check_rig;
+        self
+    }
+}
+
+/// The clips of one [`TripoClient::retarget_animations`] call, one task per animation.
+pub struct RetargetHandle {
+    client: TripoClient,
+    clips: Vec<(Animation, String)>,
+    failed: Vec<(Animation, TripoError)>,
+}
+
+impl RetargetHandle {
+    pub(crate) fn new(
+        client: TripoClient,
+        clips: Vec<(Animation, String)>,
+        failed: Vec<(Animation, TripoError)>,
+    ) -> Self {
+        Self {
+            client,
+            clips,
+            failed,
+        }
+    }
+
+    /// Returns each submitted animation with the ID of the task producing its clip.
+    pub fn clips(&self) -> &[(Animation, String)] {
+        &self.clips
+    }
+
+    /// Returns each animation whose submission failed, with the error.
+    ///
+    /// Only the [clips](Self::clips) are waited for and downloaded.
+    pub fn failed(&self) -> &[(Animation, TripoError)] {
+        &self.failed
+    }
+
+    /// Waits until every clip has finished.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if polling any of the tasks fails.
+    pub async fn wait(&self) -> Result<Vec<(Animation, TaskStatus)>, TripoError> {
+        let waits = self.clips.iter().map(|(animation, task_id)| async move {
+            let status = self.client.wait_for_task(task_id, false).await?;
+            Ok((*animation, status))
+        });
+        future::try_join_all(waits).await
+    }
+
+    /// Downloads every finished clip into a subdirectory of `dest_dir` named after its
+    /// animation, e.g. `walk` or `quadruped_walk`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if fetching a task or downloading a clip fails.
+    pub async fn download(
+        &self,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<Vec<(Animation, Vec<PathBuf>)>, TripoError> {
+        let dest_dir = dest_dir.as_ref();
+        let downloads = self.clips.iter().map(|(animation, task_id)| async move {
+            let status = self.client.get_task(task_id).await?;
+            let dir = dest_dir.join(animation.dir_name());
+            let paths = self.client.download_all_models(&status, dir).await?;
+            Ok((*animation, paths))
+        });
+        future::try_join_all(downloads).await
+    }
+}
@@ -0,0 +1,126 @@
+//! The core API calls as a trait.
+//!
+//! Code that only needs to submit, poll and download tasks can depend on
+//! [`TripoApi`] instead of [`TripoClient`] directly. With the `mocks` feature
+//! enabled, `MockTripoClient` implements the trait with
+//! [`mockall`](https://docs.rs/mockall) expectations, so downstream unit tests can
+//! stub SDK calls without starting an HTTP server.
+
+use crate::client::TripoClient;
+use crate::error::TripoError;
+use crate::types::{
+    Balance, Pagination, ResultFile, TaskFilter, TaskList, TaskRequest, TaskResponse, TaskStatus,
+};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+/// The task and account calls of the Tripo3D API.
+///
+/// Each method behaves like the [`TripoClient`] method of the same name.
+#[async_trait]
+pub trait TripoApi: Send + Sync {
+    /// See [`TripoClient::submit_task`].
+    async fn submit_task(&self, request: &TaskRequest) -> Result<TaskResponse, TripoError>;
+
+    /// See [`TripoClient::text_to_model`].
+    async fn text_to_model(&self, prompt: &str) -> Result<TaskResponse, TripoError>;
+
+    /// See [`TripoClient::image_to_model`].
+    async fn image_to_model(&self, image: &str) -> Result<TaskResponse, TripoError>;
+
+    /// See [`TripoClient::get_task`].
+    async fn get_task(&self, task_id: &str) -> Result<TaskStatus, TripoError>;
+
+    /// See [`TripoClient::wait_for_task`].
+    async fn wait_for_task(&self, task_id: &str, verbose: bool) -> Result<TaskStatus, TripoError>;
+
+    /// See [`TripoClient::list_tasks`].
+    async fn list_tasks(
+        &self,
+        filter: &TaskFilter,
+        pagination: Pagination,
+    ) -> Result<TaskList, TripoError>;
+
+    /// See [`TripoClient::get_balance`].
+    async fn get_balance(&self) -> Result<Balance, TripoError>;
+
+    /// See [`TripoClient::download_model`].
+    async fn download_model(
+        &self,
+        model_file: &ResultFile,
+        dest_dir: &Path,
+    ) -> Result<PathBuf, TripoError>;
+}
+
+#[async_trait]
+impl TripoApi for TripoClient {
+    async fn submit_task(&self, request: &TaskRequest) -> Result<TaskResponse, TripoError> {
+        TripoClient::submit_task(self, request).await
+    }
+
+    async fn text_to_model(&self, prompt: &str) -> Result<TaskResponse, TripoError> {
+        TripoClient::text_to_model(self, prompt).await
+    }
+
+    async fn image_to_model(&self, image: &str) -> Result<TaskResponse, TripoError> {
+        TripoClient::image_to_model(self, image).await
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<TaskStatus, TripoError> {
+        TripoClient::get_task(self, task_id).await
+    }
+
+    async fn wait_for_task(&self, task_id: &str, verbose: bool) -> Result<TaskStatus, TripoError> {
+        TripoClient::wait_for_task(self, task_id, verbose).await
+    }
+
+    async fn list_tasks(
+        &self,
+        filter: &TaskFilter,
+        pagination: Pagination,
+    ) -> Result<TaskList, TripoError> {
+        TripoClient::list_tasks(self, filter, pagination).await
+    }
+
+    async fn get_balance(&self) -> Result<Balance, TripoError> {
+        TripoClient::get_balance(self).await
+    }
+
+    async fn download_model(
+        &self,
+        model_file: &ResultFile,
+        dest_dir: &Path,
+    ) -> Result<PathBuf, TripoError> {
+        TripoClient::download_model(self, model_file, dest_dir).await
+    }
+}
+
+#[cfg(feature = "mocks")]
+mockall::mock! {
+    /// A [`TripoApi`] whose calls are answered by `mockall` expectations.
+    pub TripoClient {}
+
+    #[async_trait]
+    impl TripoApi for TripoClient {
+        async fn submit_task(&self, request: &TaskRequest) -> Result<TaskResponse, TripoError>;
+        async fn text_to_model(&self, prompt: &str) -> Result<TaskResponse, TripoError>;
+        async fn image_to_model(&self, image: &str) -> Result<TaskResponse, TripoError>;
+        async fn get_task(&self, task_id: &str) -> Result<TaskStatus, TripoError>;
+        async fn wait_for_task(
+            &self,
+            task_id: &str,
+            verbose: bool,
+        ) -> Result<TaskStatus, TripoError>;
+        async fn list_tasks(
+            &self,
+            filter: &TaskFilter,
+            pagination: Pagination,
+        ) -> Result<TaskList, TripoError>;
+        async fn get_balance(&self) -> Result<Balance, TripoError>;
+        async fn download_model(
+            &self,
+            model_file: &ResultFile,
+            dest_dir: &Path,
+        ) -> Result<PathBuf, TripoError>;
+    }
+}
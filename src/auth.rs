@@ -0,0 +1,144 @@
+//! Authentication with short-lived bearer tokens.
+//!
+//! By default the client signs every request with a static API key. Deployments that
+//! issue short-lived tokens instead can pass a [`TokenSource`] to
+//! [`TripoClientBuilder::token_source`](crate::TripoClientBuilder::token_source). The
+//! client caches the token it returns and fetches a new one shortly before it expires,
+//! or once the API rejects it, for REST requests and WebSocket watch handshakes alike.
+
+use crate::error::TripoError;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// How long before its expiry a cached token is replaced, so it cannot expire in flight.
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// How long a token source may take to fetch a token. Requests wait for a refresh in
+/// progress, so a hung source would otherwise stall all of them.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A bearer token and when it expires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessToken {
+    /// The token sent in the `Authorization` header.
+    pub token: String,
+    /// When the token expires, or `None` if it does not.
+    pub expires_at: Option<Instant>,
+}
+
+impl AccessToken {
+    /// Creates a token that does not expire.
+    pub fn new(token: impl Into<String>) -> Self {
+        AccessToken {
+            token: token.into(),
+            expires_at: None,
+        }
+    }
+
+    /// Sets the token to expire `lifetime` from now, e.g. from the `expires_in` of an
+    /// OAuth token response.
+    pub fn expires_in(mut self, lifetime: Duration) -> Self {
+        self.expires_at = Some(Instant::now() + lifetime);
+        self
+    }
+
+    /// Whether the token is still usable, with a margin before its expiry.
+    fn is_fresh(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| Instant::now() + REFRESH_MARGIN < expires_at)
+    }
+}
+
+/// Fetches bearer tokens on behalf of the client.
+///
+/// It is called for the first request, whenever the cached token is about to expire
+/// and after the API rejects it with 401 Unauthorized. A fetch that takes longer than
+/// 30 seconds fails the request with `TripoError::IoError`. Closures returning a future
+/// of `Result<AccessToken, TripoError>` implement it.
+pub trait TokenSource: Send + Sync {
+    /// Returns a future that resolves to a new token.
+    fn fetch(&self) -> Pin<Box<dyn Future<Output = Result<AccessToken, TripoError>> + Send + '_>>;
+}
+
+impl<F, Fut> TokenSource for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<AccessToken, TripoError>> + Send + 'static,
+{
+    fn fetch(&self) -> Pin<Box<dyn Future<Output = Result<AccessToken, TripoError>> + Send + '_>> {
+        Box::pin(self())
+    }
+}
+
+impl fmt::Debug for dyn TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenSource")
+    }
+}
+
+/// How the client authenticates its requests.
+#[derive(Clone)]
+pub(crate) enum Auth {
+    /// A static API key.
+    ApiKey(String),
+    /// Tokens from a source, with the current one cached and shared between clones.
+    Tokens {
+        source: Arc<dyn TokenSource>,
+        current: Arc<Mutex<Option<AccessToken>>>,
+    },
+}
+
+impl Auth {
+    pub(crate) fn tokens(source: Arc<dyn TokenSource>) -> Self {
+        Auth::Tokens {
+            source,
+            current: Arc::default(),
+        }
+    }
+
+    /// Returns the bearer token for the next request, refreshing an expiring one.
+    ///
+    /// Concurrent callers wait for a single refresh rather than each fetching a token.
+    pub(crate) async fn bearer(&self) -> Result<String, TripoError> {
+        match self {
+            Auth::ApiKey(api_key) => Ok(api_key.clone()),
+            Auth::Tokens { source, current } => {
+                let mut current = current.lock().await;
+                if let Some(token) = current.as_ref().filter(|token| token.is_fresh()) {
+                    return Ok(token.token.clone());
+                }
+                tracing::debug!("fetching a new access token");
+                let token = tokio::time::timeout(FETCH_TIMEOUT, source.fetch())
+                    .await
+                    .map_err(|_| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::TimedOut,
+                            "timed out fetching an access token",
+                        )
+                    })??;
+                let bearer = token.token.clone();
+                *current = Some(token);
+                Ok(bearer)
+            }
+        }
+    }
+
+    /// Drops the cached token if it is `rejected`, so the next request fetches a new
+    /// one. A token another request has already replaced is kept.
+    pub(crate) async fn invalidate(&self, rejected: &str) {
+        if let Auth::Tokens { current, .. } = self {
+            let mut current = current.lock().await;
+            if current
+                .as_ref()
+                .is_some_and(|token| token.token == rejected)
+            {
+                tracing::debug!("access token rejected, dropping it");
+                *current = None;
+            }
+        }
+    }
+}
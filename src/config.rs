@@ -0,0 +1,157 @@
+//! Loading client settings from a `tripo.toml` configuration file.
+//!
+//! The file is looked up in the following order, and the first one found is used:
+//! 1. The path in the `TRIPO_CONFIG` environment variable.
+//! 2. `tripo.toml` in the current directory.
+//! 3. `$XDG_CONFIG_HOME/tripo/tripo.toml`, falling back to `~/.config/tripo/tripo.toml`.
+//!
+//! A missing file is not an error; the defaults are used instead. Environment
+//! variables take precedence over values from the file.
+//!
+//! ```toml
+//! api_key_file = "/run/secrets/tripo_api_key"
+//! base_url = "https://api.tripo3d.ai/v2/openapi/"
+//! timeout_secs = 60
+//! connect_timeout_secs = 10
+//! read_timeout_secs = 30
+//! download_dir = "models"
+//! cache_dir = ".tripo-cache"
+//!
+//! [defaults]
+//! model_version = "v2.5-20250123"
+//! texture_quality = "detailed"
+//! ```
+
+use crate::client::TripoClientBuilder;
+use crate::error::TripoError;
+use crate::types::TaskDefaults;
+use serde::Deserialize;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CONFIG_FILE_NAME: &str = "tripo.toml";
+
+/// Client settings read from a `tripo.toml` file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TripoConfig {
+    /// The API key. Prefer `api_key_file` to keep secrets out of the config file.
+    pub api_key: Option<String>,
+    /// A file whose trimmed contents are the API key.
+    pub api_key_file: Option<PathBuf>,
+    /// The base URL for the API.
+    pub base_url: Option<String>,
+    /// The overall timeout for each HTTP request, in seconds.
+    pub timeout_secs: Option<u64>,
+    /// The timeout for establishing each connection, in seconds.
+    pub connect_timeout_secs: Option<u64>,
+    /// How long a response may go without delivering data, in seconds.
+    pub read_timeout_secs: Option<u64>,
+    /// The default directory for downloaded models.
+    pub download_dir: Option<PathBuf>,
+    /// A directory for caching downloaded assets across runs.
+    pub cache_dir: Option<PathBuf>,
+    /// Default generation options inherited by every submitted task.
+    pub defaults: Option<TaskDefaults>,
+}
+
+impl TripoConfig {
+    /// Loads the configuration from the first file found in the lookup order, applying
+    /// environment overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TripoError::ConfigError` if a config file exists but cannot be parsed.
+    pub fn load() -> Result<Self, TripoError> {
+        let mut config = match Self::discover() {
+            Some(path) => Self::from_path(path)?,
+            None => Self::default(),
+        };
+        config.apply_env();
+        Ok(config)
+    }
+
+    /// Reads the configuration from a specific file, without environment overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file cannot be read or parsed.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, TripoError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        toml::from_str(&contents)
+            .map_err(|e| TripoError::ConfigError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Returns the path of the config file that [`load`](Self::load) would read, if any.
+    pub fn discover() -> Option<PathBuf> {
+        if let Ok(path) = env::var("TRIPO_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+
+        let local = PathBuf::from(CONFIG_FILE_NAME);
+        if local.is_file() {
+            return Some(local);
+        }
+
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        let user = config_home.join("tripo").join(CONFIG_FILE_NAME);
+        user.is_file().then_some(user)
+    }
+
+    fn apply_env(&mut self) {
+        if let Ok(api_key) = env::var("TRIPO_API_KEY") {
+            self.api_key = Some(api_key);
+        }
+        if let Ok(base_url) = env::var("TRIPO_BASE_URL") {
+            self.base_url = Some(base_url);
+        }
+        if env::var_os("TRIPO_TIMEOUT").is_some() {
+            // Leave the timeout unset so the builder reads it from the environment.
+            self.timeout_secs = None;
+        }
+    }
+
+    /// Converts the configuration into a [`TripoClientBuilder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if `api_key_file` is set but cannot be read.
+    pub fn into_builder(self) -> Result<TripoClientBuilder, TripoError> {
+        let mut builder = TripoClientBuilder::default();
+
+        let api_key = match (self.api_key, self.api_key_file) {
+            (Some(api_key), _) => Some(api_key),
+            (None, Some(path)) => Some(std::fs::read_to_string(path)?.trim().to_string()),
+            (None, None) => None,
+        };
+        if let Some(api_key) = api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(base_url) = self.base_url {
+            builder = builder.base_url(base_url);
+        }
+        if let Some(timeout_secs) = self.timeout_secs {
+            builder = builder.timeout(Duration::from_secs(timeout_secs));
+        }
+        if let Some(connect_timeout_secs) = self.connect_timeout_secs {
+            builder = builder.connect_timeout(Duration::from_secs(connect_timeout_secs));
+        }
+        if let Some(read_timeout_secs) = self.read_timeout_secs {
+            builder = builder.read_timeout(Duration::from_secs(read_timeout_secs));
+        }
+        if let Some(download_dir) = self.download_dir {
+            builder = builder.download_dir(download_dir);
+        }
+        if let Some(cache_dir) = self.cache_dir {
+            builder = builder.cache_dir(cache_dir);
+        }
+        if let Some(defaults) = self.defaults {
+            builder = builder.defaults(defaults);
+        }
+        Ok(builder)
+    }
+}
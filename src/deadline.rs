@@ -0,0 +1,74 @@
+//! Overall time limits for operations made of several API calls.
+//!
+//! A [`Deadline`] is a point in time rather than a duration, so passing the same one
+//! through submission, waiting and downloading bounds all of them together. Composite
+//! operations such as
+//! [`TripoClient::generate_and_download`](crate::TripoClient::generate_and_download)
+//! take one and fail with [`TripoError::DeadlineExceeded`] once it passes.
+
+use crate::error::TripoError;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// The point in time by which an operation must finish.
+///
+/// Deadlines follow tokio's clock, so they also advance under
+/// [paused time](tokio::time::pause).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Deadline {
+    at: Option<Instant>,
+}
+
+impl Deadline {
+    /// Returns a deadline `timeout` from now.
+    pub fn after(timeout: Duration) -> Self {
+        Self::at(Instant::now() + timeout)
+    }
+
+    /// Returns a deadline at `instant`.
+    pub fn at(instant: Instant) -> Self {
+        Self { at: Some(instant) }
+    }
+
+    /// Returns a deadline that never passes.
+    pub fn never() -> Self {
+        Self::default()
+    }
+
+    /// Returns the time left until the deadline, or `None` if it never passes.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.at
+            .map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Returns whether the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining() == Some(Duration::ZERO)
+    }
+
+    /// Returns `TripoError::DeadlineExceeded` if the deadline has passed.
+    ///
+    /// Operations call this before a request that must not be cut off once sent, such
+    /// as a task submission, whose task would otherwise be created but never returned.
+    pub fn check(&self) -> Result<(), TripoError> {
+        if self.is_expired() {
+            return Err(TripoError::DeadlineExceeded);
+        }
+        Ok(())
+    }
+
+    /// Runs `future` to completion unless the deadline passes first, in which case the
+    /// future is dropped and `TripoError::DeadlineExceeded` is returned.
+    pub async fn run<T>(
+        &self,
+        future: impl Future<Output = Result<T, TripoError>>,
+    ) -> Result<T, TripoError> {
+        match self.at {
+            Some(at) => tokio::time::timeout_at(at, future)
+                .await
+                .map_err(|_| TripoError::DeadlineExceeded)?,
+            None => future.await,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! An opt-in index of previously submitted generation requests.
+//!
+//! With a [`DedupeIndex`] configured through
+//! [`TripoClientBuilder::dedupe_index`](crate::TripoClientBuilder::dedupe_index),
+//! [`TripoClient::generate`](crate::TripoClient::generate) returns the existing task
+//! for a request it has already submitted instead of paying for the same generation
+//! twice.
+
+use crate::error::TripoError;
+use crate::types::TaskRequest;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Mutex as AsyncMutex;
+
+/// Maps a hash of a normalized request to the task it created.
+///
+/// Clones share the same entries.
+#[derive(Debug, Clone, Default)]
+pub struct DedupeIndex {
+    entries: Arc<Mutex<HashMap<String, String>>>,
+    path: Option<PathBuf>,
+    /// Serializes writes of the index file so an older snapshot never overwrites a
+    /// newer one.
+    writing: Arc<AsyncMutex<()>>,
+}
+
+impl DedupeIndex {
+    /// Creates an empty in-memory index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an index persisted as JSON to `path`, loading existing entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file exists but cannot be read or parsed.
+    pub fn persistent(path: impl AsRef<Path>) -> Result<Self, TripoError> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            entries: Arc::new(Mutex::new(entries)),
+            path: Some(path),
+            writing: Arc::default(),
+        })
+    }
+
+    /// Returns the index key of a request.
+    ///
+    /// Prompts are compared case-insensitively with surrounding and repeated whitespace
+    /// ignored; every other option must match exactly.
+    pub fn key_for(request: &TaskRequest) -> String {
+        let mut request = request.clone();
+        if let TaskRequest::TextToModel(text_request) = &mut request {
+            text_request.prompt = normalize_prompt(&text_request.prompt);
+        }
+        let canonical =
+            serde_json::to_string(&request).expect("task requests always serialize to JSON");
+        format!("{:x}", Sha256::digest(canonical.as_bytes()))
+    }
+
+    /// Returns the task previously created for `key`, if any.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    /// Records that `key` created `task_id`, persisting the index if it is file-backed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the index file cannot be written.
+    pub async fn insert(&self, key: String, task_id: String) -> Result<(), TripoError> {
+        self.update(|entries| {
+            entries.insert(key, task_id);
+        })
+        .await
+    }
+
+    /// Forgets the task recorded for `key`, persisting the index if it is file-backed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the index file cannot be written.
+    pub async fn remove(&self, key: &str) -> Result<(), TripoError> {
+        self.update(|entries| {
+            entries.remove(key);
+        })
+        .await
+    }
+
+    /// Applies `change` to the entries and writes the result to the index file, without
+    /// holding the entries lock during the write.
+    async fn update(
+        &self,
+        change: impl FnOnce(&mut HashMap<String, String>),
+    ) -> Result<(), TripoError> {
+        let Some(path) = &self.path else {
+            change(&mut self.entries.lock().unwrap());
+            return Ok(());
+        };
+        let _writing = self.writing.lock().await;
+        let contents = {
+            let mut entries = self.entries.lock().unwrap();
+            change(&mut entries);
+            serde_json::to_string_pretty(&*entries)?
+        };
+        tokio::fs::write(path, contents).await?;
+        Ok(())
+    }
+}
+
+fn normalize_prompt(prompt: &str) -> String {
+    prompt
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
@@ -0,0 +1,277 @@
+//! `permessage-deflate` (RFC 7692) support for watch connections.
+//!
+//! tungstenite rejects frames with the RSV1 bit set, so [`DeflateStream`] sits between
+//! the socket and the WebSocket stack. Once the server accepts the extension in its
+//! handshake response, compressed messages are reassembled, inflated and handed on as
+//! ordinary uncompressed frames. The client never compresses what it sends.
+
+use flate2::{Decompress, FlushDecompress, Status};
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// The largest message inflated, matching tungstenite's default message size limit.
+const MAX_MESSAGE_SIZE: usize = 64 << 20;
+
+/// The empty stored block the sender strips from the end of every compressed message.
+const MESSAGE_TAIL: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("permessage-deflate: {message}"),
+    )
+}
+
+/// One raw DEFLATE stream shared by every message, so a server that keeps its context
+/// can refer back into earlier messages.
+struct Inflater {
+    decompress: Decompress,
+}
+
+impl Default for Inflater {
+    fn default() -> Self {
+        Inflater {
+            decompress: Decompress::new(false),
+        }
+    }
+}
+
+impl Inflater {
+    /// Inflates one message, whose trailing empty stored block was stripped by the sender.
+    fn inflate(&mut self, payload: &[u8]) -> io::Result<Vec<u8>> {
+        let mut input = Vec::with_capacity(payload.len() + MESSAGE_TAIL.len());
+        input.extend_from_slice(payload);
+        input.extend_from_slice(&MESSAGE_TAIL);
+        let start = self.decompress.total_in();
+        let mut out = Vec::with_capacity(input.len() * 4);
+        loop {
+            if out.len() == out.capacity() {
+                out.reserve(out.len());
+            }
+            let (consumed, produced) = ((self.decompress.total_in() - start) as usize, out.len());
+            let status = self
+                .decompress
+                .decompress_vec(&input[consumed..], &mut out, FlushDecompress::Sync)
+                .map_err(|e| invalid(&e.to_string()))?;
+            if out.len() > MAX_MESSAGE_SIZE {
+                return Err(invalid("message too large"));
+            }
+            if status == Status::StreamEnd {
+                // A final block ends the stream; the next message starts a new one.
+                self.decompress.reset(false);
+                return Ok(out);
+            }
+            let progress = self.decompress.total_in() - start;
+            if progress as usize == input.len() && out.len() < out.capacity() {
+                return Ok(out);
+            }
+            if progress as usize == consumed && out.len() == produced {
+                return Err(invalid("truncated message"));
+            }
+        }
+    }
+}
+
+enum Phase {
+    /// Reading the HTTP handshake response.
+    Handshake,
+    /// The server accepted `permessage-deflate`; compressed frames are inflated.
+    Frames,
+    /// The server declined the extension; bytes pass through untouched.
+    Passthrough,
+}
+
+/// A socket that inflates the server's `permessage-deflate` frames before tungstenite
+/// sees them. Writes pass through unchanged.
+pub(crate) struct DeflateStream<S> {
+    inner: S,
+    phase: Phase,
+    /// Bytes read from the socket and not yet handed on.
+    input: Vec<u8>,
+    /// Bytes ready to be read by the WebSocket stack.
+    output: Vec<u8>,
+    output_pos: usize,
+    /// The opcode and compressed payload of a fragmented message being reassembled.
+    message: Option<(u8, Vec<u8>)>,
+    inflater: Inflater,
+}
+
+impl<S> DeflateStream<S> {
+    pub(crate) fn new(inner: S) -> Self {
+        DeflateStream {
+            inner,
+            phase: Phase::Handshake,
+            input: Vec::new(),
+            output: Vec::new(),
+            output_pos: 0,
+            message: None,
+            inflater: Inflater::default(),
+        }
+    }
+
+    /// Moves the handshake response or the next complete frame from the input to the
+    /// output. Returns `false` when more input is needed.
+    fn process(&mut self) -> io::Result<bool> {
+        match self.phase {
+            Phase::Handshake => {
+                let Some(end) = self.input.windows(4).position(|w| w == b"\r\n\r\n") else {
+                    return Ok(false);
+                };
+                let response: Vec<u8> = self.input.drain(..end + 4).collect();
+                let accepted = String::from_utf8_lossy(&response).lines().any(|line| {
+                    let line = line.to_ascii_lowercase();
+                    line.starts_with("sec-websocket-extensions:")
+                        && line.contains("permessage-deflate")
+                });
+                self.output.extend_from_slice(&response);
+                self.phase = if accepted {
+                    Phase::Frames
+                } else {
+                    Phase::Passthrough
+                };
+                Ok(true)
+            }
+            Phase::Passthrough => {
+                let moved = !self.input.is_empty();
+                self.output.append(&mut self.input);
+                Ok(moved)
+            }
+            Phase::Frames => {
+                let Some((header_len, payload_len)) = frame_size(&self.input)? else {
+                    return Ok(false);
+                };
+                let frame: Vec<u8> = self.input.drain(..header_len + payload_len).collect();
+                self.frame(frame, header_len)?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Hands on a control or uncompressed frame as is, and collects the fragments of a
+    /// compressed message until its last one, then hands on the inflated message.
+    fn frame(&mut self, frame: Vec<u8>, header_len: usize) -> io::Result<()> {
+        let (fin, rsv1, opcode) = (frame[0] & 0x80 != 0, frame[0] & 0x40 != 0, frame[0] & 0x0f);
+        let mut payload = frame[header_len..].to_vec();
+        if frame[1] & 0x80 != 0 {
+            let mask = &frame[header_len - 4..header_len];
+            for (i, byte) in payload.iter_mut().enumerate() {
+                *byte ^= mask[i % 4];
+            }
+        }
+        match (&mut self.message, rsv1, opcode) {
+            // Control frames may come between fragments and never end a message.
+            (_, _, 0x8..) | (None, false, _) => {
+                self.output.extend_from_slice(&frame);
+                return Ok(());
+            }
+            (None, true, 0x1 | 0x2) => self.message = Some((opcode, payload)),
+            (Some((_, data)), false, 0x0) => data.extend_from_slice(&payload),
+            _ => return Err(invalid("unexpected frame in compressed message")),
+        }
+        if !fin {
+            return Ok(());
+        }
+        let Some((opcode, data)) = self.message.take() else {
+            return Ok(());
+        };
+        let data = self.inflater.inflate(&data)?;
+        self.output.push(0x80 | opcode);
+        match data.len() {
+            len @ 0..=125 => self.output.push(len as u8),
+            len @ 126..=0xffff => {
+                self.output.push(126);
+                self.output.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            len => {
+                self.output.push(127);
+                self.output.extend_from_slice(&(len as u64).to_be_bytes());
+            }
+        }
+        self.output.extend_from_slice(&data);
+        Ok(())
+    }
+}
+
+/// The header and payload length of the first frame in `input`, once it is complete.
+fn frame_size(input: &[u8]) -> io::Result<Option<(usize, usize)>> {
+    let [_, second, ..] = *input else {
+        return Ok(None);
+    };
+    let mask_len = if second & 0x80 != 0 { 4 } else { 0 };
+    let (header_len, payload_len) = match second & 0x7f {
+        126 => match input.get(2..4) {
+            Some(len) => (4, u64::from(u16::from_be_bytes([len[0], len[1]]))),
+            None => return Ok(None),
+        },
+        127 => match input.get(2..10) {
+            Some(len) => (10, u64::from_be_bytes(len.try_into().expect("eight bytes"))),
+            None => return Ok(None),
+        },
+        len => (2, u64::from(len)),
+    };
+    if payload_len > MAX_MESSAGE_SIZE as u64 {
+        return Err(invalid("frame too large"));
+    }
+    let (header_len, payload_len) = (header_len + mask_len, payload_len as usize);
+    if input.len() < header_len + payload_len {
+        return Ok(None);
+    }
+    Ok(Some((header_len, payload_len)))
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for DeflateStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.output_pos < this.output.len() {
+                let len = buf.remaining().min(this.output.len() - this.output_pos);
+                buf.put_slice(&this.output[this.output_pos..this.output_pos + len]);
+                this.output_pos += len;
+                if this.output_pos == this.output.len() {
+                    this.output.clear();
+                    this.output_pos = 0;
+                }
+                return Poll::Ready(Ok(()));
+            }
+            if this.process()? {
+                continue;
+            }
+            let mut chunk = [0u8; 8192];
+            let mut read = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut read))?;
+            if read.filled().is_empty() {
+                // End of stream: hand on any partial frame so tungstenite reports it.
+                if this.input.is_empty() {
+                    return Poll::Ready(Ok(()));
+                }
+                this.output.append(&mut this.input);
+                continue;
+            }
+            this.input.extend_from_slice(read.filled());
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for DeflateStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
@@ -1,13 +1,44 @@
 use thiserror::Error;
 
 /// The primary error type for the Tripo3D SDK.
+///
+/// New variants may be added in minor releases, so matches on it need a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum TripoError {
     /// The API key was not provided.
     /// It must be supplied during client creation or set via the `TRIPO_API_KEY` environment variable.
     #[error("API key is missing. Please provide it or set the TRIPO_API_KEY environment variable.")]
     MissingApiKey,
 
+    /// The API key was rejected by the server (HTTP 401 or 403).
+    #[error(
+        "API key is invalid or lacks permission. Please check the key on the Tripo3D platform."
+    )]
+    InvalidApiKey,
+
+    /// The account does not have enough credits to submit the task while keeping the
+    /// configured minimum balance in reserve.
+    #[error("Insufficient credits: {available} available, {required} required")]
+    InsufficientCredits { available: f64, required: f64 },
+
+    /// A prompt was rejected locally before submission, e.g. because it is empty or too long.
+    #[error("Invalid prompt: {0}")]
+    InvalidPrompt(String),
+
+    /// A request parameter was rejected locally before submission because it is outside
+    /// the range the API accepts.
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+
+    /// A prompt template could not be rendered.
+    #[error("Prompt template error: {0}")]
+    TemplateError(String),
+
+    /// A configuration file could not be parsed.
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+
     /// A network request failed. This is often a wrapper around a `reqwest::Error`.
     #[error("Network request failed: {0}")]
     RequestError(#[from] reqwest::Error),
@@ -17,8 +48,15 @@ pub enum TripoError {
     ResponseParseError(#[from] serde_json::Error),
 
     /// The Tripo3D API returned an error. The message contains the details from the API.
+    ///
+    /// `code` is the Tripo error code from the response body, if there was one; prefer
+    /// reading it with [`api_error_code`](Self::api_error_code). See
+    /// [`ApiErrorCode::category`] for what it means.
     #[error("API request failed: {message}")]
-    ApiError { message: String },
+    ApiError {
+        message: String,
+        code: Option<ApiErrorCode>,
+    },
 
     /// A URL could not be parsed. This can happen with an invalid base URL or a malformed URL from the API.
     #[error("URL parsing failed: {0}")]
@@ -36,7 +74,255 @@ pub enum TripoError {
     #[error("WebSocket error: {0}")]
     WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
 
+    /// A downloaded file is not what its extension claims, e.g. an HTML error page saved
+    /// as `.glb` after the download URL expired. The file is removed.
+    #[error("Corrupt download {path}: {reason}")]
+    CorruptDownload {
+        path: std::path::PathBuf,
+        reason: String,
+    },
+
+    /// A watch stream buffered more updates than its capacity allows and was closed.
+    #[error("Watch stream overflowed its buffer of {capacity} updates")]
+    WatchOverflow { capacity: usize },
+
+    /// Requests to an endpoint were refused without being sent because it failed
+    /// repeatedly. See [`CircuitBreaker`](crate::CircuitBreaker).
+    #[error("Circuit open for {endpoint}, retry in {retry_after:?}")]
+    CircuitOpen {
+        endpoint: String,
+        retry_after: std::time::Duration,
+    },
+
+    /// A composite operation did not finish before its [`Deadline`](crate::Deadline).
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
+
+    /// A task finished in the failure state.
+    #[error("Task {task_id} failed")]
+    TaskFailed { task_id: String },
+
+    /// A task was submitted but could not be recorded locally, e.g. in a
+    /// [`DedupeIndex`](crate::DedupeIndex). The task still runs on the server; `task_id`
+    /// identifies it and `source` is why recording failed.
+    #[error("Task {task_id} was submitted but could not be recorded: {source}")]
+    Unrecorded {
+        task_id: String,
+        source: Box<TripoError>,
+    },
+
+    /// A task succeeded without producing the asset an operation needed.
+    #[error("Task {task_id} produced no {} asset", kind.name())]
+    MissingAsset {
+        task_id: String,
+        kind: crate::types::AssetKind,
+    },
+
+    /// The input was rejected by content moderation, e.g. a banned prompt or image.
+    /// `reason` is the message the API gave.
+    #[error("Content rejected: {reason}")]
+    ContentRejected { reason: String },
+
+    /// A draft model was rejected by the approval callback of
+    /// [`TripoClient::generate_refined`](crate::TripoClient::generate_refined).
+    #[error("Draft {task_id} was rejected")]
+    DraftRejected { task_id: String },
+
+    /// A rig check found that a model cannot be rigged.
+    #[error("Model of task {task_id} cannot be rigged: {}", reasons.join(", "))]
+    NotRiggable {
+        task_id: String,
+        reasons: Vec<String>,
+    },
+
+    /// The [`TaskManager`](crate::TaskManager) has been shut down and accepts no new
+    /// work.
+    #[error("Task manager is shut down")]
+    ShutDown,
+
+    /// A proxy refused or failed to tunnel a connection.
+    #[error("Proxy connection failed: {0}")]
+    ProxyError(String),
+
     /// An HTTP request could not be built.
     #[error("Failed to build HTTP request: {0}")]
     HttpError(#[from] tokio_tungstenite::tungstenite::http::Error),
-} 
\ No newline at end of file
+}
+
+impl TripoError {
+    /// Builds the error for an API error response body, surfacing a
+    /// [`ContentRejected`](Self::ContentRejected) for moderation codes.
+    pub(crate) fn from_api_body(message: String, body: &serde_json::Value) -> Self {
+        let code = ApiErrorCode::from_body(body);
+        if code.is_some_and(|code| code.category() == ErrorCategory::Moderation) {
+            let reason = body
+                .get("message")
+                .and_then(serde_json::Value::as_str)
+                .map_or(message, str::to_string);
+            return TripoError::ContentRejected { reason };
+        }
+        TripoError::ApiError { message, code }
+    }
+
+    /// Builds the error for a task that finished without succeeding, surfacing a
+    /// [`ContentRejected`](Self::ContentRejected) for tasks refused by moderation.
+    pub(crate) fn from_failed_task(status: &crate::types::TaskStatus) -> Self {
+        if status.status == crate::types::TaskState::Banned {
+            return TripoError::ContentRejected {
+                reason: format!("task {} was refused by content moderation", status.task_id),
+            };
+        }
+        TripoError::TaskFailed {
+            task_id: status.task_id.clone(),
+        }
+    }
+
+    /// Returns the Tripo error code if this is an [`ApiError`](Self::ApiError) that
+    /// carried one.
+    ///
+    /// Moderation codes are surfaced as [`ContentRejected`](Self::ContentRejected)
+    /// instead, so this returns `None` for them.
+    pub fn api_error_code(&self) -> Option<ApiErrorCode> {
+        match self {
+            TripoError::ApiError { code, .. } => *code,
+            _ => None,
+        }
+    }
+}
+
+/// The broad cause of an [`ApiErrorCode`], for choosing how to react to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The API key is missing, invalid or lacks permission.
+    Auth,
+    /// The account is out of credits or over its rate limit.
+    Quota,
+    /// The request or its input files were rejected.
+    InvalidInput,
+    /// The input was refused by content moderation.
+    Moderation,
+    /// The API failed on its side; retrying later may succeed.
+    Server,
+    /// The code is not in the documented table.
+    Unknown,
+}
+
+/// A numeric error code returned by the Tripo3D API, e.g. `2010` for insufficient
+/// credits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiErrorCode(pub i64);
+
+/// Documented error codes with their category and remediation.
+const API_ERROR_CODES: &[(i64, ErrorCategory, &str)] = &[
+    (
+        1000,
+        ErrorCategory::Server,
+        "The server hit an error. Retry the request later.",
+    ),
+    (
+        1001,
+        ErrorCategory::Server,
+        "The server hit a fatal error. Contact Tripo support if it persists.",
+    ),
+    (
+        1002,
+        ErrorCategory::Auth,
+        "Check that the API key is correct and has not been revoked.",
+    ),
+    (
+        1003,
+        ErrorCategory::InvalidInput,
+        "The request body is malformed. Check the request fields.",
+    ),
+    (
+        1004,
+        ErrorCategory::InvalidInput,
+        "A parameter is invalid. Check the request fields against the API documentation.",
+    ),
+    (
+        1005,
+        ErrorCategory::Auth,
+        "The API key is not allowed to perform this action.",
+    ),
+    (
+        2000,
+        ErrorCategory::Quota,
+        "Too many generation requests. Slow down and retry later.",
+    ),
+    (
+        2001,
+        ErrorCategory::InvalidInput,
+        "The task does not exist. Check the task ID.",
+    ),
+    (
+        2002,
+        ErrorCategory::InvalidInput,
+        "The task type is not supported.",
+    ),
+    (
+        2003,
+        ErrorCategory::InvalidInput,
+        "The input file is empty. Upload a non-empty file.",
+    ),
+    (
+        2004,
+        ErrorCategory::InvalidInput,
+        "The file type is not supported. Use a JPEG, PNG or WebP image.",
+    ),
+    (
+        2006,
+        ErrorCategory::InvalidInput,
+        "The original task has a type that cannot be used for this task.",
+    ),
+    (
+        2007,
+        ErrorCategory::InvalidInput,
+        "The original task has not succeeded. Wait for it to finish first.",
+    ),
+    (
+        2008,
+        ErrorCategory::Moderation,
+        "The input violates the content policy. Change the prompt or image.",
+    ),
+    (
+        2010,
+        ErrorCategory::Quota,
+        "The account does not have enough credits. Top up and try again.",
+    ),
+    (
+        2015,
+        ErrorCategory::InvalidInput,
+        "The model version is deprecated. Use a current model version.",
+    ),
+    (
+        2018,
+        ErrorCategory::InvalidInput,
+        "The model is too complex to process. Try a simpler model.",
+    ),
+];
+
+impl ApiErrorCode {
+    /// Reads the `code` field of an API error response body.
+    pub(crate) fn from_body(body: &serde_json::Value) -> Option<Self> {
+        body.get("code")
+            .and_then(serde_json::Value::as_i64)
+            .map(ApiErrorCode)
+    }
+
+    fn entry(self) -> Option<&'static (i64, ErrorCategory, &'static str)> {
+        API_ERROR_CODES.iter().find(|(code, _, _)| *code == self.0)
+    }
+
+    /// Returns the category of the code, or [`ErrorCategory::Unknown`] if it is not
+    /// documented.
+    pub fn category(self) -> ErrorCategory {
+        self.entry()
+            .map_or(ErrorCategory::Unknown, |(_, category, _)| *category)
+    }
+
+    /// Returns a message telling the user how to resolve the error, if the code is
+    /// documented.
+    pub fn suggestion(self) -> Option<&'static str> {
+        self.entry().map(|(_, _, suggestion)| *suggestion)
+    }
+}
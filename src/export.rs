@@ -0,0 +1,170 @@
+//! Task history reports for audits and billing reconciliation.
+//!
+//! A [`TaskReportWriter`] writes one [`TaskReportRow`] per task as JSON Lines or CSV.
+//! Rows come from the API through
+//! [`TripoClient::export_tasks`](crate::TripoClient::export_tasks), or from a local
+//! [`TaskStore`](crate::TaskStore) by converting its records:
+//!
+//! ```no_run
+//! # fn run(store: tripo3d::TaskStore) -> Result<(), tripo3d::TripoError> {
+//! use tripo3d::export::{ExportFormat, TaskReportWriter};
+//!
+//! let file = std::fs::File::create("tasks.csv")?;
+//! let mut report = TaskReportWriter::new(file, ExportFormat::Csv);
+//! for record in store.records() {
+//!     report.write(&(&record).into())?;
+//! }
+//! report.finish()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::TripoError;
+use crate::store::TaskRecord;
+use crate::types::{TaskState, TaskStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// The file format of a task report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line.
+    JsonLines,
+    /// Comma-separated values with a header row. Output URLs are separated by spaces.
+    Csv,
+}
+
+/// One task in a report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskReportRow {
+    /// The ID of the task.
+    pub task_id: String,
+    /// The task type, e.g. "text_to_model", if known.
+    #[serde(rename = "type")]
+    pub task_type: Option<String>,
+    /// The last known state of the task.
+    pub status: Option<TaskState>,
+    /// The credits the task consumed, if known.
+    pub cost: Option<f64>,
+    /// When the task was created.
+    pub created_at: DateTime<Utc>,
+    /// The URLs of the task's output files.
+    pub output_urls: Vec<String>,
+}
+
+impl From<&TaskStatus> for TaskReportRow {
+    fn from(status: &TaskStatus) -> Self {
+        TaskReportRow {
+            task_id: status.task_id.clone(),
+            task_type: status.task_type.clone(),
+            status: Some(status.status),
+            cost: status.consumed_credit,
+            created_at: DateTime::from_timestamp(status.create_time as i64, 0).unwrap_or_default(),
+            output_urls: status
+                .result
+                .files()
+                .into_iter()
+                .map(|(_, file)| file.url.clone())
+                .collect(),
+        }
+    }
+}
+
+impl From<&TaskRecord> for TaskReportRow {
+    fn from(record: &TaskRecord) -> Self {
+        TaskReportRow {
+            task_id: record.task_id.clone(),
+            task_type: Some(record.task_type.clone()),
+            status: record.state,
+            cost: None,
+            created_at: record.submitted_at,
+            output_urls: Vec::new(),
+        }
+    }
+}
+
+/// Writes [`TaskReportRow`]s to `W` in an [`ExportFormat`].
+pub struct TaskReportWriter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    rows: usize,
+}
+
+impl<W: Write> TaskReportWriter<W> {
+    /// Creates a writer. Nothing is written until the first row, or
+    /// [`finish`](Self::finish) for an empty report.
+    pub fn new(writer: W, format: ExportFormat) -> Self {
+        TaskReportWriter {
+            writer,
+            format,
+            rows: 0,
+        }
+    }
+
+    /// Writes one row.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if writing fails.
+    pub fn write(&mut self, row: &TaskReportRow) -> Result<(), TripoError> {
+        if self.rows == 0 {
+            self.write_header()?;
+        }
+        match self.format {
+            ExportFormat::JsonLines => {
+                serde_json::to_writer(&mut self.writer, row)?;
+                self.writer.write_all(b"\n")?;
+            }
+            ExportFormat::Csv => {
+                let fields = [
+                    row.task_id.clone(),
+                    row.task_type.clone().unwrap_or_default(),
+                    row.status
+                        .map(|state| state.as_str().to_string())
+                        .unwrap_or_default(),
+                    row.cost.map(|cost| cost.to_string()).unwrap_or_default(),
+                    row.created_at.to_rfc3339(),
+                    row.output_urls.join(" "),
+                ];
+                let line: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+                writeln!(self.writer, "{}", line.join(","))?;
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Writes the header of an empty report, flushes the writer and returns the number of
+    /// rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if writing fails.
+    pub fn finish(mut self) -> Result<usize, TripoError> {
+        if self.rows == 0 {
+            self.write_header()?;
+        }
+        self.writer.flush()?;
+        Ok(self.rows)
+    }
+
+    fn write_header(&mut self) -> Result<(), TripoError> {
+        if self.format == ExportFormat::Csv {
+            writeln!(
+                self.writer,
+                "task_id,type,status,cost,created_at,output_urls"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
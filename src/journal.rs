@@ -0,0 +1,224 @@
+//! An append-only record of the jobs run by a [`TaskManager`](crate::TaskManager).
+//!
+//! A job submits one task, waits for it and downloads its models. With a [`Journal`]
+//! configured through [`TaskManager::journal`](crate::TaskManager::journal), every
+//! stage a job reaches is appended to a file before the job moves on, so after a crash
+//! [`TaskManager::resume_jobs`](crate::TaskManager::resume_jobs) continues each
+//! unfinished job from its last recorded stage instead of starting over.
+
+use crate::error::TripoError;
+use crate::types::TaskRequest;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// How far a job has progressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStage {
+    /// The job was accepted but its task has not been submitted yet.
+    Queued,
+    /// The task was accepted by the API.
+    Submitted,
+    /// The task is being polled until it finishes.
+    Waiting,
+    /// The task finished successfully and its models are being downloaded.
+    Downloading,
+    /// The models were downloaded.
+    Done,
+    /// The task finished unsuccessfully.
+    Failed,
+}
+
+impl JobStage {
+    /// Returns whether the job has nothing left to do.
+    pub fn is_finished(self) -> bool {
+        matches!(self, JobStage::Done | JobStage::Failed)
+    }
+}
+
+/// The state of a job, as replayed from the journal.
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// The caller-chosen ID of the job.
+    pub id: String,
+    /// The request the job submits.
+    pub request: TaskRequest,
+    /// The directory the job downloads the models into.
+    pub dest_dir: PathBuf,
+    /// The ID of the submitted task, once there is one.
+    pub task_id: Option<String>,
+    /// The last recorded stage.
+    pub stage: JobStage,
+    /// When the last stage was recorded.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One line of the journal file.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Entry {
+    job: String,
+    stage: JobStage,
+    at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request: Option<TaskRequest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dest_dir: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    task_id: Option<String>,
+}
+
+/// An append-only journal of job stages, stored as JSON lines.
+///
+/// Clones share the same file.
+#[derive(Debug, Clone)]
+pub struct Journal {
+    file: Arc<Mutex<File>>,
+    jobs: Arc<Mutex<BTreeMap<String, Job>>>,
+}
+
+impl Journal {
+    /// Opens the journal at `path`, creating it if needed, and replays its entries.
+    ///
+    /// A truncated last line, left by a crash in the middle of a write, is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file cannot be read or created, or contains an
+    /// entry that cannot be parsed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, TripoError> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut jobs = BTreeMap::new();
+        let mut valid_len = 0;
+        for line in contents.split_inclusive('\n') {
+            let entry = match serde_json::from_str::<Entry>(line) {
+                Ok(entry) => entry,
+                Err(_) if !line.ends_with('\n') => break,
+                Err(e) => return Err(e.into()),
+            };
+            apply(&mut jobs, entry);
+            valid_len += line.len();
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if valid_len < contents.len() {
+            // Drop the truncated line so later entries start on a line of their own.
+            file.set_len(valid_len as u64)?;
+        } else if !contents.is_empty() && !contents.ends_with('\n') {
+            file.write_all(b"\n")?;
+        }
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            jobs: Arc::new(Mutex::new(jobs)),
+        })
+    }
+
+    /// Returns all jobs in the journal, ordered by ID.
+    pub fn jobs(&self) -> Vec<Job> {
+        self.jobs.lock().unwrap().values().cloned().collect()
+    }
+
+    /// Returns the jobs that have not finished, ordered by ID.
+    pub fn pending(&self) -> Vec<Job> {
+        self.jobs()
+            .into_iter()
+            .filter(|job| !job.stage.is_finished())
+            .collect()
+    }
+
+    /// Returns the job with the given ID, if it is in the journal.
+    pub fn get(&self, id: &str) -> Option<Job> {
+        self.jobs.lock().unwrap().get(id).cloned()
+    }
+
+    /// Records that a new job was queued.
+    pub(crate) async fn queue(
+        &self,
+        id: &str,
+        request: &TaskRequest,
+        dest_dir: &Path,
+    ) -> Result<(), TripoError> {
+        self.append(Entry {
+            job: id.to_string(),
+            stage: JobStage::Queued,
+            at: Utc::now(),
+            request: Some(request.clone()),
+            dest_dir: Some(dest_dir.to_path_buf()),
+            task_id: None,
+        })
+        .await
+    }
+
+    /// Records that a job reached `stage`, along with its task ID if it just got one.
+    pub(crate) async fn advance(
+        &self,
+        id: &str,
+        stage: JobStage,
+        task_id: Option<&str>,
+    ) -> Result<(), TripoError> {
+        self.append(Entry {
+            job: id.to_string(),
+            stage,
+            at: Utc::now(),
+            request: None,
+            dest_dir: None,
+            task_id: task_id.map(str::to_string),
+        })
+        .await
+    }
+
+    /// Writes `entry` to disk on the blocking pool before applying it, so the file is
+    /// never behind.
+    async fn append(&self, entry: Entry) -> Result<(), TripoError> {
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+        let file = self.file.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut file = file.lock().unwrap();
+            file.write_all(line.as_bytes())?;
+            file.sync_data()
+        })
+        .await
+        .map_err(io::Error::other)??;
+        apply(&mut self.jobs.lock().unwrap(), entry);
+        Ok(())
+    }
+}
+
+/// Folds an entry into the replayed job states.
+fn apply(jobs: &mut BTreeMap<String, Job>, entry: Entry) {
+    match (jobs.get_mut(&entry.job), entry.request, entry.dest_dir) {
+        (_, Some(request), Some(dest_dir)) => {
+            jobs.insert(
+                entry.job.clone(),
+                Job {
+                    id: entry.job,
+                    request,
+                    dest_dir,
+                    task_id: entry.task_id,
+                    stage: entry.stage,
+                    updated_at: entry.at,
+                },
+            );
+        }
+        (Some(job), _, _) => {
+            if entry.task_id.is_some() {
+                job.task_id = entry.task_id;
+            }
+            job.stage = entry.stage;
+            job.updated_at = entry.at;
+        }
+        // An update for a job whose queued entry is missing cannot be resumed.
+        (None, _, _) => {}
+    }
+}
@@ -8,13 +8,72 @@
 //! - Text-to-model and image-to-model generation.
 //! - Asynchronous API for non-blocking operations.
 //! - Task polling to wait for generation completion.
+//! - WebSocket task watching with filters and a resumable cursor.
 //! - Helper functions for downloading generated models.
+//! - Budget-aware batch submission through the [`TaskManager`].
+//! - Resumable generate, refine, rig, animate, convert and download pipelines through
+//!   [`Workflow`].
+//! - A local task store with caller-defined metadata and lifecycle events.
+//! - A client-wide retry budget and per-endpoint circuit breaker.
 //! - Typed error handling for robust applications.
 
+pub mod animation;
+pub mod api;
+pub mod auth;
 pub mod client;
+pub mod config;
+pub mod deadline;
+pub mod dedupe;
+mod deflate;
 pub mod error;
+pub mod export;
+pub mod journal;
+pub mod manager;
+pub mod preset;
+pub mod pricing;
+pub mod prompt;
+pub mod resilience;
+pub mod scheduler;
+pub mod sleeper;
+pub mod store;
+#[cfg(feature = "test-support")]
+pub mod testing;
 pub mod types;
+pub mod watch;
+pub mod workflow;
 
-pub use client::TripoClient;
-pub use error::TripoError;
-pub use types::{Balance, ResultFile, TaskResponse, TaskResult, TaskState, TaskStatus};
+pub use animation::{AnimateOptions, Animation, RetargetHandle, RigCheckResult, RigType};
+#[cfg(feature = "mocks")]
+pub use api::MockTripoClient;
+pub use api::TripoApi;
+pub use auth::{AccessToken, TokenSource};
+pub use client::{Candidate, DownloadReport, RefineOptions, TripoClient, TripoClientBuilder};
+pub use config::TripoConfig;
+pub use deadline::Deadline;
+pub use dedupe::DedupeIndex;
+pub use error::{ApiErrorCode, ErrorCategory, TripoError};
+pub use export::{ExportFormat, TaskReportRow, TaskReportWriter};
+pub use journal::{Job, JobStage, Journal};
+pub use manager::{
+    BatchReport, EventHandler, ShutdownOptions, ShutdownReport, SubmitOptions, TaskEvent,
+    TaskHandle, TaskManager,
+};
+pub use preset::{Preset, Quality};
+pub use pricing::PricingTable;
+pub use prompt::{PromptLanguage, PromptTemplate};
+pub use resilience::{CircuitBreaker, RateLimit, RetryBudget};
+pub use scheduler::Priority;
+pub use sleeper::{Sleeper, TokioSleeper};
+pub use store::{TaskRecord, TaskStore};
+pub use types::{
+    Account, AccountLimits, AssetFilter, AssetKind, Balance, Compression, ConvertModelRequest,
+    ConvertOptions, CreditEstimate, DownloadEvent, Environment, FileContent, FileNaming,
+    GenerateOptions, ImageTaskRequest, ModelOrientation, MultiviewRequest, Organization,
+    Orientation, OutputFormat, Pagination, Pivot, PollSchedule, RefineRequest, ResultFile,
+    RetargetRequest, RetopologyRequest, RigCheckRequest, RigFormat, RigRequest, Stylization,
+    StylizeRequest, TaskDefaults, TaskFilter, TaskList, TaskOutput, TaskRequest, TaskResponse,
+    TaskResult, TaskState, TaskStatus, TextToModelRequest, TextureFormat, TexturePrompt,
+    TextureQuality, TimeRange, Unit, UploadEvent, UsageHistory, UsageRecord, WatchFilter,
+};
+pub use watch::{OverflowPolicy, WatchCursor, WatchTransport};
+pub use workflow::{StepState, Workflow, WorkflowState, WorkflowStep};
@@ -0,0 +1,803 @@
+//! Batch submission on top of [`TripoClient`].
+//!
+//! The [`TaskManager`] submits many task requests with bounded concurrency and can
+//! cap the credits a single run is allowed to spend. Submissions waiting for a free
+//! slot start in order of their [`Priority`]. Every submission is recorded in
+//! a local [`TaskStore`] and announced as a [`TaskEvent`] to subscribers and
+//! registered [`EventHandler`]s.
+//!
+//! [`TaskManager::shutdown`] stops a manager gracefully, e.g. when a service receives
+//! SIGTERM. [`TaskManager::run_job`] takes a task from submission to download and,
+//! with a [`Journal`] configured, can be resumed after a crash.
+
+use crate::client::TripoClient;
+use crate::deadline::Deadline;
+use crate::error::TripoError;
+use crate::journal::{JobStage, Journal};
+use crate::scheduler::{Priority, Scheduler};
+use crate::store::{TaskRecord, TaskStore};
+use crate::types::{Balance, TaskRequest, TaskResponse, TaskState, TaskStatus};
+use chrono::Utc;
+use futures_util::{stream, StreamExt};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, Notify};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+const EVENT_CAPACITY: usize = 256;
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Submits batches of tasks with a concurrency cap and an optional credit budget.
+#[derive(Clone)]
+pub struct TaskManager {
+    client: TripoClient,
+    concurrency: usize,
+    scheduler: Arc<Scheduler>,
+    batch_priority: Priority,
+    budget: Option<f64>,
+    store: TaskStore,
+    events: broadcast::Sender<TaskEvent>,
+    handlers: Vec<Arc<dyn EventHandler>>,
+    lifecycle: Arc<Lifecycle>,
+    journal: Option<Journal>,
+}
+
+/// Tracks the work in flight so [`TaskManager::shutdown`] can wait for it.
+#[derive(Default)]
+struct Lifecycle {
+    closed: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+/// Marks one submission or download as in flight until dropped.
+struct InFlight(Arc<Lifecycle>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+/// Caller-side options for a single [`TaskManager::submit`].
+#[derive(Debug, Clone, Default)]
+pub struct SubmitOptions {
+    /// A human-readable name for looking the task up later.
+    pub name: Option<String>,
+    /// Key/value metadata stored with the task and included in its events.
+    pub metadata: BTreeMap<String, String>,
+    /// How urgently the submission should start when the concurrency cap is reached.
+    pub priority: Priority,
+}
+
+impl SubmitOptions {
+    /// Creates empty submit options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names the task, e.g. "kitchen-chair-v3".
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Attaches a metadata entry, replacing any previous value for `key`.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the priority of the submission, e.g. [`Priority::High`] for a request a
+    /// user is waiting for.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Options for [`TaskManager::shutdown`].
+#[derive(Debug, Clone)]
+pub struct ShutdownOptions {
+    /// How long to wait for in-flight submissions and downloads. Defaults to 30 seconds.
+    pub timeout: Duration,
+    /// Where to save the [`TaskStore`], if anywhere.
+    pub persist_to: Option<PathBuf>,
+}
+
+impl Default for ShutdownOptions {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            persist_to: None,
+        }
+    }
+}
+
+impl ShutdownOptions {
+    /// Creates the default shutdown options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long to wait for in-flight work.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Saves the task store to `path`, to be restored with [`TaskStore::load`].
+    pub fn persist_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.persist_to = Some(path.into());
+        self
+    }
+}
+
+/// The outcome of a [`TaskManager::shutdown`].
+#[derive(Debug, Default)]
+pub struct ShutdownReport {
+    /// Whether all in-flight work finished before the timeout.
+    pub drained: bool,
+}
+
+/// A lifecycle event of a task submitted through a [`TaskManager`].
+#[derive(Debug, Clone)]
+pub enum TaskEvent {
+    /// The task was accepted by the API.
+    Submitted(TaskRecord),
+    /// The task was polled while still pending or running.
+    Progress {
+        /// The stored record, including the observed state.
+        record: TaskRecord,
+        /// The status reported by the API.
+        status: TaskStatus,
+    },
+    /// The task reached a final state.
+    Finished {
+        /// The stored record, including the final state.
+        record: TaskRecord,
+        /// The final status reported by the API.
+        status: TaskStatus,
+    },
+    /// The task's models were downloaded.
+    Downloaded {
+        /// The stored record.
+        record: TaskRecord,
+        /// The paths of the downloaded files.
+        paths: Vec<PathBuf>,
+    },
+}
+
+impl TaskEvent {
+    /// Returns the stored record the event refers to.
+    pub fn record(&self) -> &TaskRecord {
+        match self {
+            TaskEvent::Submitted(record) => record,
+            TaskEvent::Progress { record, .. } => record,
+            TaskEvent::Finished { record, .. } => record,
+            TaskEvent::Downloaded { record, .. } => record,
+        }
+    }
+}
+
+/// Callbacks for the lifecycle of tasks submitted through a [`TaskManager`].
+///
+/// Every method has an empty default, so implementations only override the events
+/// they care about. Handlers run inline on the task's future and should return quickly.
+///
+/// # Examples
+///
+/// ```
+/// use tripo3d::{EventHandler, TaskRecord, TaskStatus};
+///
+/// struct AuditLog;
+///
+/// impl EventHandler for AuditLog {
+///     fn on_success(&self, record: &TaskRecord, _status: &TaskStatus) {
+///         println!("task {} finished", record.task_id);
+///     }
+/// }
+/// ```
+pub trait EventHandler: Send + Sync {
+    /// Called after the API accepted a task.
+    fn on_submitted(&self, _record: &TaskRecord) {}
+
+    /// Called for every poll that finds the task still pending or running.
+    fn on_progress(&self, _record: &TaskRecord, _status: &TaskStatus) {}
+
+    /// Called when the task finished successfully.
+    fn on_success(&self, _record: &TaskRecord, _status: &TaskStatus) {}
+
+    /// Called when the task finished in any state other than success.
+    fn on_failure(&self, _record: &TaskRecord, _status: &TaskStatus) {}
+
+    /// Called after the task's models were downloaded.
+    fn on_downloaded(&self, _record: &TaskRecord, _paths: &[PathBuf]) {}
+}
+
+/// A task submitted through a [`TaskManager`].
+#[derive(Clone)]
+pub struct TaskHandle {
+    task_id: String,
+    manager: TaskManager,
+}
+
+impl TaskHandle {
+    /// Returns the ID of the task.
+    pub fn task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    /// Returns the locally stored record of the task.
+    pub fn record(&self) -> Option<TaskRecord> {
+        self.manager.store.get(&self.task_id)
+    }
+
+    /// Returns the name given at submission, if any.
+    pub fn name(&self) -> Option<String> {
+        self.record().and_then(|record| record.name)
+    }
+
+    /// Returns the metadata attached at submission.
+    pub fn metadata(&self) -> BTreeMap<String, String> {
+        self.record()
+            .map(|record| record.metadata)
+            .unwrap_or_default()
+    }
+
+    /// Polls the task until it finishes, records the final state and emits a
+    /// [`TaskEvent::Finished`].
+    pub async fn wait(&self) -> Result<TaskStatus, TripoError> {
+        self.wait_within(Deadline::never()).await
+    }
+
+    /// Waits like [`wait`](Self::wait), failing with `TripoError::DeadlineExceeded` if
+    /// the task has not finished by `deadline`.
+    pub async fn wait_within(&self, deadline: Deadline) -> Result<TaskStatus, TripoError> {
+        let wait = self
+            .manager
+            .client
+            .wait_for_task_with(&self.task_id, |status| {
+                if !matches!(status.status, TaskState::Pending | TaskState::Running) {
+                    return;
+                }
+                if let Some(record) = self
+                    .manager
+                    .store
+                    .update_state(&self.task_id, status.status)
+                {
+                    self.manager.emit(TaskEvent::Progress {
+                        record,
+                        status: status.clone(),
+                    });
+                }
+            });
+        let status = deadline.run(wait).await?;
+        if let Some(record) = self
+            .manager
+            .store
+            .update_state(&self.task_id, status.status)
+        {
+            self.manager.emit(TaskEvent::Finished {
+                record,
+                status: status.clone(),
+            });
+        }
+        self.manager.client.refresh_low_balance().await;
+        Ok(status)
+    }
+
+    /// Downloads all models of the finished task into `dest_dir` and emits a
+    /// [`TaskEvent::Downloaded`].
+    pub async fn download<P: AsRef<Path>>(&self, dest_dir: P) -> Result<Vec<PathBuf>, TripoError> {
+        let _in_flight = self.manager.begin()?;
+        let status = self.manager.client.get_task(&self.task_id).await?;
+        let paths = self
+            .manager
+            .client
+            .download_all_models(&status, dest_dir)
+            .await?;
+        if let Some(record) = self.record() {
+            self.manager.emit(TaskEvent::Downloaded {
+                record,
+                paths: paths.clone(),
+            });
+        }
+        Ok(paths)
+    }
+}
+
+/// The outcome of a [`TaskManager::submit_batch`] run.
+///
+/// Every entry is keyed by the index of the request in the submitted batch.
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// Requests that were submitted successfully.
+    pub submitted: Vec<(usize, TaskResponse)>,
+    /// Requests that were attempted but rejected.
+    pub failed: Vec<(usize, TripoError)>,
+    /// Requests that were not attempted because the budget was exhausted.
+    pub skipped: Vec<usize>,
+    /// The estimated credits of all attempted requests.
+    pub estimated_credits: f64,
+}
+
+impl TaskManager {
+    /// Creates a manager that submits through `client`.
+    pub fn new(client: TripoClient) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            client,
+            concurrency: DEFAULT_CONCURRENCY,
+            scheduler: Arc::new(Scheduler::new(DEFAULT_CONCURRENCY)),
+            batch_priority: Priority::default(),
+            budget: None,
+            store: TaskStore::new(),
+            events,
+            handlers: Vec::new(),
+            lifecycle: Arc::default(),
+            journal: None,
+        }
+    }
+
+    /// Sets the maximum number of submissions in flight at once. Defaults to 4.
+    ///
+    /// The cap is shared by [`submit`](Self::submit), [`submit_batch`](Self::submit_batch)
+    /// and all clones of the manager.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self.scheduler = Arc::new(Scheduler::new(self.concurrency));
+        self
+    }
+
+    /// Sets the priority of submissions made by [`submit_batch`](Self::submit_batch).
+    /// Defaults to [`Priority::Normal`].
+    ///
+    /// Use [`Priority::Low`] for bulk work that should yield to individual submissions.
+    pub fn batch_priority(mut self, priority: Priority) -> Self {
+        self.batch_priority = priority;
+        self
+    }
+
+    /// Sets the maximum number of credits a single batch may spend.
+    ///
+    /// Requests are scheduled in order until the estimated cumulative cost would exceed
+    /// the budget. That request and all following ones are reported as skipped.
+    pub fn budget(mut self, credits: f64) -> Self {
+        self.budget = Some(credits);
+        self
+    }
+
+    /// Calls `callback` the first time the available balance drops below `threshold`
+    /// credits, checking the balance after every task [`TaskHandle::wait`] finishes.
+    ///
+    /// See [`TripoClientBuilder::on_low_balance`](crate::TripoClientBuilder::on_low_balance).
+    pub fn on_low_balance(
+        mut self,
+        threshold: f64,
+        callback: impl Fn(&Balance) + Send + Sync + 'static,
+    ) -> Self {
+        self.client = self.client.with_low_balance_hook(threshold, callback);
+        self
+    }
+
+    /// Registers a handler that is called for the lifecycle events of every task.
+    pub fn event_handler(mut self, handler: impl EventHandler + 'static) -> Self {
+        self.handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// Replaces the local record of submitted tasks, e.g. with one restored by
+    /// [`TaskStore::load`] after a restart.
+    pub fn task_store(mut self, store: TaskStore) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Records the stages of jobs started with [`run_job`](Self::run_job) in `journal`,
+    /// so they can be resumed with [`resume_jobs`](Self::resume_jobs) after a crash.
+    pub fn journal(mut self, journal: Journal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Returns the client used for submissions.
+    pub fn client(&self) -> &TripoClient {
+        &self.client
+    }
+
+    /// Returns the local record of submitted tasks.
+    pub fn store(&self) -> &TaskStore {
+        &self.store
+    }
+
+    /// Subscribes to the lifecycle events of tasks submitted from now on.
+    ///
+    /// A subscriber that falls more than 256 events behind misses the oldest ones.
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskEvent> {
+        self.events.subscribe()
+    }
+
+    /// Returns a handle to the most recently submitted task with the given name.
+    pub fn find_task_by_name(&self, name: &str) -> Option<TaskHandle> {
+        let record = self.store.find_by_name(name)?;
+        Some(TaskHandle {
+            task_id: record.task_id,
+            manager: self.clone(),
+        })
+    }
+
+    /// Resumes waiting on a task after a restart, e.g. one recorded in a
+    /// [`TaskStore`] restored with [`TaskStore::load`].
+    ///
+    /// Waits like [`TaskHandle::wait`], so the task's record is updated and its
+    /// [`TaskEvent::Finished`] is emitted again. Events are only emitted for tasks
+    /// in the store.
+    pub async fn resume_wait(&self, task_id: &str) -> Result<TaskStatus, TripoError> {
+        let handle = TaskHandle {
+            task_id: task_id.to_string(),
+            manager: self.clone(),
+        };
+        handle.wait().await
+    }
+
+    /// Resumes waiting on every task in the store that is not known to have finished,
+    /// so no paid generation is orphaned by a restart.
+    ///
+    /// At most [`concurrency`](Self::concurrency) tasks are waited on at once. Returns
+    /// the outcome of each wait, keyed by task ID, in the order the waits finish.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tripo3d::{TaskManager, TaskStore, TripoClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let client = TripoClient::new(Some("your_api_key".to_string()))?;
+    /// let manager = TaskManager::new(client).task_store(TaskStore::load("tasks.json")?);
+    /// for (task_id, status) in manager.resume_waits().await {
+    ///     println!("{task_id}: {:?}", status?.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resume_waits(&self) -> Vec<(String, Result<TaskStatus, TripoError>)> {
+        let waits = self
+            .store
+            .unfinished()
+            .into_iter()
+            .map(|record| async move {
+                let status = self.resume_wait(&record.task_id).await;
+                (record.task_id, status)
+            });
+        stream::iter(waits)
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Submits a single task with caller-side options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tripo3d::{SubmitOptions, TaskManager, TextToModelRequest, TripoClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let client = TripoClient::new(Some("your_api_key".to_string()))?;
+    /// let manager = TaskManager::new(client);
+    /// let handle = manager
+    ///     .submit(
+    ///         &TextToModelRequest::new("a wooden chair").into(),
+    ///         SubmitOptions::new().metadata("asset_id", "chair-42"),
+    ///     )
+    ///     .await?;
+    /// let status = handle.wait().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit(
+        &self,
+        request: &TaskRequest,
+        options: SubmitOptions,
+    ) -> Result<TaskHandle, TripoError> {
+        let _in_flight = self.begin()?;
+        let _permit = self.scheduler.acquire(options.priority).await;
+        let response = self.client.submit_task(request).await?;
+        Ok(self.record_submission(request, &response, options))
+    }
+
+    fn record_submission(
+        &self,
+        request: &TaskRequest,
+        response: &TaskResponse,
+        options: SubmitOptions,
+    ) -> TaskHandle {
+        let record = TaskRecord {
+            task_id: response.task_id.clone(),
+            task_type: request.task_type().to_string(),
+            name: options.name,
+            metadata: options.metadata,
+            submitted_at: Utc::now(),
+            state: None,
+        };
+        self.store.insert(record.clone());
+        self.emit(TaskEvent::Submitted(record));
+        TaskHandle {
+            task_id: response.task_id.clone(),
+            manager: self.clone(),
+        }
+    }
+
+    /// Submits `request`, waits for the task and downloads its models into `dest_dir`.
+    ///
+    /// `job_id` identifies the job in the [journal](Self::journal), which records every
+    /// stage before the job moves on to the next one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TripoError::TaskFailed` if the task finishes unsuccessfully,
+    /// `TripoError::ContentRejected` if content moderation refuses it,
+    /// `TripoError::Unrecorded` with the task's ID if the task was submitted but the
+    /// journal could not record it, or another `TripoError` if a step fails. A job that
+    /// failed for any reason other than its task failing stays unfinished in the journal
+    /// and is retried by [`resume_jobs`](Self::resume_jobs).
+    pub async fn run_job(
+        &self,
+        job_id: impl Into<String>,
+        request: TaskRequest,
+        dest_dir: impl Into<PathBuf>,
+    ) -> Result<Vec<PathBuf>, TripoError> {
+        let job_id = job_id.into();
+        let dest_dir = dest_dir.into();
+        if let Some(journal) = &self.journal {
+            journal.queue(&job_id, &request, &dest_dir).await?;
+        }
+        self.drive_job(&job_id, &request, &dest_dir, None, JobStage::Queued)
+            .await
+    }
+
+    /// Continues every unfinished job in the journal from its last recorded stage.
+    ///
+    /// Returns the outcome of each job, keyed by job ID. Without a journal there is
+    /// nothing to resume.
+    ///
+    /// A job that crashed after its task was accepted but before that was recorded is
+    /// submitted again. Configure a [`DedupeIndex`](crate::DedupeIndex) to avoid paying
+    /// for it twice.
+    pub async fn resume_jobs(&self) -> Vec<(String, Result<Vec<PathBuf>, TripoError>)> {
+        let Some(journal) = &self.journal else {
+            return Vec::new();
+        };
+        let jobs = journal.pending();
+        stream::iter(jobs)
+            .map(|job| async move {
+                let result = self
+                    .drive_job(
+                        &job.id,
+                        &job.request,
+                        &job.dest_dir,
+                        job.task_id.as_deref(),
+                        job.stage,
+                    )
+                    .await;
+                (job.id, result)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Runs a job from `stage` to the end, recording each stage it reaches.
+    async fn drive_job(
+        &self,
+        job_id: &str,
+        request: &TaskRequest,
+        dest_dir: &Path,
+        task_id: Option<&str>,
+        stage: JobStage,
+    ) -> Result<Vec<PathBuf>, TripoError> {
+        let handle = match task_id {
+            Some(task_id) if stage != JobStage::Queued => TaskHandle {
+                task_id: task_id.to_string(),
+                manager: self.clone(),
+            },
+            _ => {
+                let handle = self.submit(request, SubmitOptions::default()).await?;
+                let recorded = self
+                    .record(job_id, JobStage::Submitted, Some(handle.task_id()))
+                    .await;
+                if let Err(e) = recorded {
+                    return Err(TripoError::Unrecorded {
+                        task_id: handle.task_id().to_string(),
+                        source: Box::new(e),
+                    });
+                }
+                handle
+            }
+        };
+
+        if stage != JobStage::Downloading {
+            self.record(job_id, JobStage::Waiting, None).await?;
+            let status = handle.wait().await?;
+            if status.status != TaskState::Success {
+                self.record(job_id, JobStage::Failed, None).await?;
+                return Err(TripoError::from_failed_task(&status));
+            }
+            self.record(job_id, JobStage::Downloading, None).await?;
+        }
+        let paths = handle.download(dest_dir).await?;
+        self.record(job_id, JobStage::Done, None).await?;
+        Ok(paths)
+    }
+
+    /// Records that a job reached `stage` in the journal, if there is one.
+    async fn record(
+        &self,
+        job_id: &str,
+        stage: JobStage,
+        task_id: Option<&str>,
+    ) -> Result<(), TripoError> {
+        match &self.journal {
+            Some(journal) => journal.advance(job_id, stage, task_id).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Registers a submission or download, unless the manager is shut down.
+    fn begin(&self) -> Result<InFlight, TripoError> {
+        self.lifecycle.in_flight.fetch_add(1, Ordering::SeqCst);
+        let in_flight = InFlight(self.lifecycle.clone());
+        if self.lifecycle.closed.load(Ordering::SeqCst) {
+            return Err(TripoError::ShutDown);
+        }
+        Ok(in_flight)
+    }
+
+    /// Shuts the manager down gracefully.
+    ///
+    /// New submissions and downloads, through this manager or its clones, fail with
+    /// `TripoError::ShutDown` from now on. The manager then waits up to
+    /// [`ShutdownOptions::timeout`] for those in flight and saves the task store.
+    /// Tasks that have not finished keep running on the server; restore the store with
+    /// [`TaskStore::load`] and [`resume_waits`](Self::resume_waits) to pick them up.
+    ///
+    /// Watch streams are not affected; end them with
+    /// [`TripoClient::close_watchers`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use tripo3d::{ShutdownOptions, TaskManager};
+    /// # async fn run(manager: TaskManager) -> Result<(), tripo3d::TripoError> {
+    /// tokio::signal::ctrl_c().await?;
+    /// let report = manager
+    ///     .shutdown(
+    ///         ShutdownOptions::new()
+    ///             .timeout(Duration::from_secs(10))
+    ///             .persist_to("tasks.json"),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the task store cannot be saved.
+    pub async fn shutdown(&self, options: ShutdownOptions) -> Result<ShutdownReport, TripoError> {
+        self.lifecycle.closed.store(true, Ordering::SeqCst);
+        let lifecycle = &self.lifecycle;
+        let drained = async {
+            loop {
+                // Created before the check, so a wakeup in between is not missed.
+                let idle = lifecycle.idle.notified();
+                if lifecycle.in_flight.load(Ordering::SeqCst) == 0 {
+                    break;
+                }
+                idle.await;
+            }
+        };
+        let report = ShutdownReport {
+            drained: tokio::time::timeout(options.timeout, drained).await.is_ok(),
+        };
+
+        if let Some(path) = options.persist_to {
+            let store = self.store.clone();
+            tokio::task::spawn_blocking(move || store.save(path))
+                .await
+                .map_err(io::Error::other)??;
+        }
+        Ok(report)
+    }
+
+    fn emit(&self, event: TaskEvent) {
+        for handler in &self.handlers {
+            match &event {
+                TaskEvent::Submitted(record) => handler.on_submitted(record),
+                TaskEvent::Progress { record, status } => handler.on_progress(record, status),
+                TaskEvent::Finished { record, status } if status.status == TaskState::Success => {
+                    handler.on_success(record, status)
+                }
+                TaskEvent::Finished { record, status } => handler.on_failure(record, status),
+                TaskEvent::Downloaded { record, paths } => handler.on_downloaded(record, paths),
+            }
+        }
+        // Sending only fails when nobody is subscribed.
+        let _ = self.events.send(event);
+    }
+
+    /// Submits a batch of task requests.
+    ///
+    /// A failing request does not abort the batch; its error is recorded in the
+    /// returned [`BatchReport`].
+    pub async fn submit_batch(&self, requests: Vec<TaskRequest>) -> BatchReport {
+        self.submit_batch_within(requests, Deadline::never()).await
+    }
+
+    /// Submits a batch like [`submit_batch`](Self::submit_batch), bounded by `deadline`.
+    ///
+    /// Requests that have not been sent when the deadline passes are recorded as failed
+    /// with `TripoError::DeadlineExceeded`. A submission already sent is never cut off,
+    /// so every task the batch creates is in the report.
+    pub async fn submit_batch_within(
+        &self,
+        requests: Vec<TaskRequest>,
+        deadline: Deadline,
+    ) -> BatchReport {
+        let mut report = BatchReport::default();
+        let mut scheduled = Vec::new();
+
+        for (index, request) in requests.into_iter().enumerate() {
+            let cost = self.client.estimate_cost(&request).credits;
+            let over_budget = self
+                .budget
+                .is_some_and(|budget| report.estimated_credits + cost > budget);
+            if over_budget || !report.skipped.is_empty() {
+                report.skipped.push(index);
+                continue;
+            }
+            report.estimated_credits += cost;
+            scheduled.push((index, request));
+        }
+
+        let mut results = stream::iter(scheduled)
+            .map(|(index, request)| async move {
+                let result = match self.begin() {
+                    Ok(_in_flight) => {
+                        let permit = deadline
+                            .run(async { Ok(self.scheduler.acquire(self.batch_priority).await) })
+                            .await;
+                        match permit.and_then(|permit| deadline.check().map(|()| permit)) {
+                            Ok(_permit) => self.client.submit_task(&request).await,
+                            Err(e) => Err(e),
+                        }
+                    }
+                    Err(e) => Err(e),
+                };
+                (index, request, result)
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some((index, request, result)) = results.next().await {
+            match result {
+                Ok(response) => {
+                    self.record_submission(&request, &response, SubmitOptions::default());
+                    report.submitted.push((index, response));
+                }
+                Err(e) => report.failed.push((index, e)),
+            }
+        }
+
+        report.submitted.sort_by_key(|(index, _)| *index);
+        report.failed.sort_by_key(|(index, _)| *index);
+        report
+    }
+}
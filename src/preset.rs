@@ -0,0 +1,188 @@
+//! Preset bundles of generation and export settings.
+//!
+//! A [`Preset`] sets several related options at once, so callers get sensible output
+//! for a target platform without knowing every parameter. A [`Quality`] level does the
+//! same for the speed/quality tradeoff.
+
+use crate::types::{
+    Compression, GenerateOptions, ImageTaskRequest, OutputFormat, TextToModelRequest,
+    TextureQuality,
+};
+
+/// The fast, lower-fidelity model version used by [`Quality::Draft`] and
+/// [`TextToModelRequest::fast`].
+pub const TURBO_MODEL_VERSION: &str = "Turbo-v1.0-20250506";
+
+/// The highest-fidelity model version used by [`Quality::High`] and
+/// [`TextToModelRequest::hd`].
+pub const HIGH_QUALITY_MODEL_VERSION: &str = "v2.5-20250123";
+
+/// The model version of the drafts made by
+/// [`TripoClient::generate_refined`](crate::TripoClient::generate_refined) by default.
+/// Refinement only accepts drafts of this version or older.
+pub const REFINABLE_MODEL_VERSION: &str = "v1.4-20240625";
+
+/// A one-knob speed/quality tradeoff for generation tasks.
+///
+/// Each level maps onto the model version and texture quality. Apply it with
+/// [`TextToModelRequest::with_quality`] or [`ImageTaskRequest::with_quality`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quality {
+    /// The fastest and cheapest results, for previews and iteration.
+    Draft,
+    /// The server defaults.
+    #[default]
+    Standard,
+    /// The best available model and detailed textures, at a higher cost.
+    High,
+}
+
+impl Quality {
+    /// The model version to request, or `None` to leave the server default.
+    pub fn model_version(self) -> Option<&'static str> {
+        match self {
+            Quality::Draft => Some(TURBO_MODEL_VERSION),
+            Quality::Standard => None,
+            Quality::High => Some(HIGH_QUALITY_MODEL_VERSION),
+        }
+    }
+
+    /// The texture quality to request.
+    pub fn texture_quality(self) -> TextureQuality {
+        match self {
+            Quality::Draft | Quality::Standard => TextureQuality::Standard,
+            Quality::High => TextureQuality::Detailed,
+        }
+    }
+}
+
+/// A bundle of settings tuned for a common target platform.
+///
+/// Generation options are applied with [`TextToModelRequest::with_preset`] and
+/// [`ImageTaskRequest::with_preset`]. The export settings, [`texture_size`](Self::texture_size)
+/// and [`output_format`](Self::output_format), are applied to the conversion with
+/// [`GenerateOptions::with_preset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Low-poly, compressed models with small textures for mobile games.
+    MobileGame,
+    /// High-detail models with detailed textures for close-up hero assets.
+    HeroAsset,
+    /// Moderately dense models for Apple AR Quick Look.
+    ArQuickLook,
+}
+
+impl Preset {
+    /// The maximum number of faces, or `None` to leave the server default.
+    pub fn face_limit(self) -> Option<u32> {
+        match self {
+            Preset::MobileGame => Some(5_000),
+            Preset::HeroAsset => None,
+            Preset::ArQuickLook => Some(20_000),
+        }
+    }
+
+    /// The texture quality requested at generation time.
+    pub fn texture_quality(self) -> TextureQuality {
+        match self {
+            Preset::MobileGame | Preset::ArQuickLook => TextureQuality::Standard,
+            Preset::HeroAsset => TextureQuality::Detailed,
+        }
+    }
+
+    /// The compression applied at generation time, if any.
+    pub fn compression(self) -> Option<Compression> {
+        match self {
+            Preset::MobileGame => Some(Compression::Geometry),
+            Preset::HeroAsset | Preset::ArQuickLook => None,
+        }
+    }
+
+    /// The texture resolution, in pixels, expected on export.
+    pub fn texture_size(self) -> u32 {
+        match self {
+            Preset::MobileGame => 1024,
+            Preset::HeroAsset => 4096,
+            Preset::ArQuickLook => 2048,
+        }
+    }
+
+    /// The file format expected on export.
+    pub fn output_format(self) -> OutputFormat {
+        match self {
+            Preset::MobileGame => OutputFormat::Gltf,
+            Preset::HeroAsset => OutputFormat::Fbx,
+            Preset::ArQuickLook => OutputFormat::Usdz,
+        }
+    }
+}
+
+impl GenerateOptions {
+    /// Applies the export settings of a [`Preset`]: the model is converted into its
+    /// output format with its texture size, replacing any previous values.
+    pub fn with_preset(mut self, preset: Preset) -> Self {
+        self.target_format = Some(preset.output_format());
+        self.convert.texture_size = Some(preset.texture_size());
+        self
+    }
+}
+
+/// Implements the preset and quality setters shared by the generation requests.
+macro_rules! generation_settings {
+    ($($request:ty),*) => {$(
+        impl $request {
+            /// Applies the generation settings of a [`Preset`], replacing any previous values.
+            pub fn with_preset(mut self, preset: Preset) -> Self {
+                self.texture = Some(true);
+                self.texture_quality = Some(preset.texture_quality());
+                self.face_limit = preset.face_limit();
+                self.compress = preset.compression();
+                self
+            }
+
+            /// Applies a [`Quality`] level, replacing the model version and texture quality.
+            pub fn with_quality(mut self, quality: Quality) -> Self {
+                self.model_version = quality.model_version().map(str::to_string);
+                self.texture_quality = Some(quality.texture_quality());
+                self
+            }
+
+            /// Switches the turbo model version on or off, trading fidelity for latency.
+            ///
+            /// Turning it off only clears the model version if it is the turbo one.
+            pub fn fast(mut self, fast: bool) -> Self {
+                if fast {
+                    self.model_version = Some(TURBO_MODEL_VERSION.to_string());
+                } else if self.model_version.as_deref() == Some(TURBO_MODEL_VERSION) {
+                    self.model_version = None;
+                }
+                self
+            }
+
+            /// Switches the high-detail mode on or off: the highest-fidelity model version with
+            /// detailed textures, for hero assets.
+            ///
+            /// Turning it off only clears the options it would have set.
+            pub fn hd(mut self, hd: bool) -> Self {
+                if hd {
+                    self.texture = Some(true);
+                    self.model_version = Some(HIGH_QUALITY_MODEL_VERSION.to_string());
+                    self.texture_quality = Some(TextureQuality::Detailed);
+                } else {
+                    if self.texture == Some(true) {
+                        self.texture = None;
+                    }
+                    if self.model_version.as_deref() == Some(HIGH_QUALITY_MODEL_VERSION) {
+                        self.model_version = None;
+                    }
+                    if self.texture_quality == Some(TextureQuality::Detailed) {
+                        self.texture_quality = None;
+                    }
+                }
+                self
+            }
+        }
+    )*};
+}
+
+generation_settings!(TextToModelRequest, ImageTaskRequest);
@@ -0,0 +1,243 @@
+//! Credit pricing for Tripo3D tasks.
+//!
+//! The constants in this module mirror the published per-task-type credit costs so
+//! that applications can show "this will cost ~N credits" before submitting work.
+//! A [`PricingTable`] bundles them together and can be refreshed from the API with
+//! [`TripoClient::refresh_pricing`](crate::TripoClient::refresh_pricing).
+
+use crate::types::{CreditEstimate, TaskRequest, TextureQuality};
+use serde::{Deserialize, Serialize};
+
+/// The credit costs of a single task type.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaskPricing {
+    /// The cost of an untextured model.
+    pub base: f64,
+    /// The additional cost of generating a standard texture.
+    pub texture: f64,
+    /// The further cost of upgrading the texture to `TextureQuality::Detailed`.
+    pub detailed_texture: f64,
+    /// The flat cost charged by legacy `v1.x` model versions, regardless of options.
+    pub legacy: f64,
+}
+
+/// Published pricing for text-to-model tasks.
+pub const TEXT_TO_MODEL: TaskPricing = TaskPricing {
+    base: 10.0,
+    texture: 10.0,
+    detailed_texture: 10.0,
+    legacy: 20.0,
+};
+
+/// Published pricing for image-to-model tasks.
+pub const IMAGE_TO_MODEL: TaskPricing = TaskPricing {
+    base: 20.0,
+    texture: 10.0,
+    detailed_texture: 10.0,
+    legacy: 30.0,
+};
+
+/// Published pricing for multiview-to-model tasks.
+pub const MULTIVIEW_TO_MODEL: TaskPricing = TaskPricing {
+    base: 20.0,
+    texture: 10.0,
+    detailed_texture: 10.0,
+    legacy: 30.0,
+};
+
+/// Published pricing for retopology tasks, which are billed at a flat rate.
+pub const RETOPOLOGY: TaskPricing = TaskPricing {
+    base: 10.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 10.0,
+};
+
+/// Published pricing for refining a draft model, which is billed at a flat rate.
+pub const REFINE_MODEL: TaskPricing = TaskPricing {
+    base: 30.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 30.0,
+};
+
+/// Published pricing for format conversions, which are billed at a flat rate.
+pub const CONVERT_MODEL: TaskPricing = TaskPricing {
+    base: 5.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 5.0,
+};
+
+/// Published pricing for stylization, which is billed at a flat rate.
+pub const STYLIZE_MODEL: TaskPricing = TaskPricing {
+    base: 20.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 20.0,
+};
+
+/// Published pricing for rig checks, which are free.
+pub const ANIMATE_PRERIGCHECK: TaskPricing = TaskPricing {
+    base: 0.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 0.0,
+};
+
+/// Published pricing for rigging, which is billed at a flat rate.
+pub const ANIMATE_RIG: TaskPricing = TaskPricing {
+    base: 25.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 25.0,
+};
+
+/// Published pricing for animation retargeting, which is billed at a flat rate.
+pub const ANIMATE_RETARGET: TaskPricing = TaskPricing {
+    base: 10.0,
+    texture: 0.0,
+    detailed_texture: 0.0,
+    legacy: 10.0,
+};
+
+/// The credit costs of every supported task type.
+///
+/// Task types missing from a refreshed table keep their published defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PricingTable {
+    /// Pricing for text-to-model tasks.
+    pub text_to_model: TaskPricing,
+    /// Pricing for image-to-model tasks.
+    pub image_to_model: TaskPricing,
+    /// Pricing for multiview-to-model tasks.
+    pub multiview_to_model: TaskPricing,
+    /// Pricing for retopology tasks.
+    pub retopology: TaskPricing,
+    /// Pricing for refining draft models.
+    pub refine_model: TaskPricing,
+    /// Pricing for format conversions.
+    pub convert_model: TaskPricing,
+    /// Pricing for stylization.
+    pub stylize_model: TaskPricing,
+    /// Pricing for rig checks.
+    pub animate_prerigcheck: TaskPricing,
+    /// Pricing for rigging.
+    pub animate_rig: TaskPricing,
+    /// Pricing for animation retargeting.
+    pub animate_retarget: TaskPricing,
+}
+
+impl Default for PricingTable {
+    fn default() -> Self {
+        Self {
+            text_to_model: TEXT_TO_MODEL,
+            image_to_model: IMAGE_TO_MODEL,
+            multiview_to_model: MULTIVIEW_TO_MODEL,
+            retopology: RETOPOLOGY,
+            refine_model: REFINE_MODEL,
+            convert_model: CONVERT_MODEL,
+            stylize_model: STYLIZE_MODEL,
+            animate_prerigcheck: ANIMATE_PRERIGCHECK,
+            animate_rig: ANIMATE_RIG,
+            animate_retarget: ANIMATE_RETARGET,
+        }
+    }
+}
+
+impl PricingTable {
+    /// Returns the pricing for an API task type string, e.g. "text_to_model".
+    pub fn for_task_type(&self, task_type: &str) -> Option<&TaskPricing> {
+        match task_type {
+            "text_to_model" => Some(&self.text_to_model),
+            "image_to_model" => Some(&self.image_to_model),
+            "multiview_to_model" => Some(&self.multiview_to_model),
+            "highpoly_to_lowpoly" => Some(&self.retopology),
+            "refine_model" => Some(&self.refine_model),
+            "convert_model" => Some(&self.convert_model),
+            "stylize_model" => Some(&self.stylize_model),
+            "animate_prerigcheck" => Some(&self.animate_prerigcheck),
+            "animate_rig" => Some(&self.animate_rig),
+            "animate_retarget" => Some(&self.animate_retarget),
+            _ => None,
+        }
+    }
+
+    /// Estimates the credit cost of a task request using this table.
+    pub fn estimate(&self, request: &TaskRequest) -> CreditEstimate {
+        let (pricing, model_version, texture, texture_quality) = match request {
+            TaskRequest::TextToModel(r) => (
+                &self.text_to_model,
+                &r.model_version,
+                r.texture,
+                r.texture_quality,
+            ),
+            TaskRequest::ImageToModel(r) => (
+                &self.image_to_model,
+                &r.model_version,
+                r.texture,
+                r.texture_quality,
+            ),
+            TaskRequest::MultiviewToModel(r) => (
+                &self.multiview_to_model,
+                &r.model_version,
+                r.texture,
+                r.texture_quality,
+            ),
+            TaskRequest::Retopology(_) => {
+                return CreditEstimate {
+                    credits: self.retopology.base,
+                }
+            }
+            TaskRequest::Refine(_) => {
+                return CreditEstimate {
+                    credits: self.refine_model.base,
+                }
+            }
+            TaskRequest::ConvertModel(_) => {
+                return CreditEstimate {
+                    credits: self.convert_model.base,
+                }
+            }
+            TaskRequest::Stylize(_) => {
+                return CreditEstimate {
+                    credits: self.stylize_model.base,
+                }
+            }
+            TaskRequest::RigCheck(_) => {
+                return CreditEstimate {
+                    credits: self.animate_prerigcheck.base,
+                }
+            }
+            TaskRequest::Rig(_) => {
+                return CreditEstimate {
+                    credits: self.animate_rig.base,
+                }
+            }
+            TaskRequest::Retarget(_) => {
+                return CreditEstimate {
+                    credits: self.animate_retarget.base,
+                }
+            }
+        };
+
+        if model_version
+            .as_deref()
+            .is_some_and(|version| version.starts_with("v1."))
+        {
+            return CreditEstimate {
+                credits: pricing.legacy,
+            };
+        }
+
+        let mut credits = pricing.base;
+        if texture.unwrap_or(true) {
+            credits += pricing.texture;
+            if texture_quality == Some(TextureQuality::Detailed) {
+                credits += pricing.detailed_texture;
+            }
+        }
+        CreditEstimate { credits }
+    }
+}
@@ -0,0 +1,189 @@
+//! Prompt templating utilities.
+//!
+//! A [`PromptTemplate`] substitutes `{name}` placeholders and wraps the result in
+//! reusable prefixes and suffixes, such as style descriptors or quality tags. A
+//! template can also be installed on the client with
+//! [`TripoClientBuilder::prompt_template`](crate::TripoClientBuilder::prompt_template)
+//! to decorate every text prompt it submits.
+//!
+//! Prompts are checked locally with [`validate_prompt`] before submission, so obviously
+//! invalid input fails fast without a round-trip to the API.
+//!
+//! Prompts need not be in English. [`PromptLanguage`] lists the languages the API
+//! accepts prompts in; the server detects the language of a prompt itself.
+
+use crate::error::TripoError;
+
+/// The maximum prompt length, in characters, accepted by the API.
+pub const MAX_PROMPT_LENGTH: usize = 1024;
+
+/// Checks that a prompt is non-blank and no longer than [`MAX_PROMPT_LENGTH`] characters.
+///
+/// # Errors
+///
+/// Returns `TripoError::InvalidPrompt` describing the problem.
+pub fn validate_prompt(prompt: &str) -> Result<(), TripoError> {
+    if prompt.trim().is_empty() {
+        return Err(TripoError::InvalidPrompt(
+            "prompt must not be empty".to_string(),
+        ));
+    }
+    let length = prompt.chars().count();
+    if length > MAX_PROMPT_LENGTH {
+        return Err(TripoError::InvalidPrompt(format!(
+            "prompt is {} characters long, the maximum is {}",
+            length, MAX_PROMPT_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+/// A language the API accepts prompts in.
+///
+/// Prompts in languages other than English are translated on the server before
+/// generation. The server guesses the language, which is unreliable for short prompts,
+/// e.g. Japanese written only in kanji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptLanguage {
+    /// English, which is used as is.
+    English,
+    /// Simplified or traditional Chinese.
+    ///
+    /// [`detect`](Self::detect) never returns it, as ideographs alone do not tell Chinese
+    /// from Japanese; it is for callers that already know a prompt's language.
+    Chinese,
+    /// Japanese.
+    Japanese,
+}
+
+impl PromptLanguage {
+    /// Guesses the language of a prompt from its script: kana means Japanese and Latin
+    /// letters alone English.
+    ///
+    /// Returns `None` for CJK ideographs without kana, which may be Chinese or Japanese
+    /// written only in kanji, and for prompts in any other script.
+    pub fn detect(prompt: &str) -> Option<Self> {
+        let is_kana = |c: char| matches!(c, '\u{3040}'..='\u{30ff}');
+        let is_han = |c: char| matches!(c, '\u{4e00}'..='\u{9fff}' | '\u{3400}'..='\u{4dbf}');
+        if prompt.chars().any(is_kana) {
+            Some(PromptLanguage::Japanese)
+        } else if prompt.chars().any(is_han) {
+            None
+        } else if prompt.chars().all(|c| c.is_ascii() || !c.is_alphabetic()) {
+            Some(PromptLanguage::English)
+        } else {
+            None
+        }
+    }
+}
+
+/// A reusable prompt with `{name}` placeholders and optional prefixes and suffixes.
+///
+/// Literal braces are written as `{{` and `}}`. Prefixes, the rendered body and suffixes
+/// are joined with `", "`, skipping empty parts.
+///
+/// # Example
+///
+/// ```
+/// # use tripo3d::PromptTemplate;
+/// let template = PromptTemplate::new("a {material} {object}")
+///     .prefix("low poly")
+///     .suffix("game asset");
+/// let prompt = template
+///     .render(&[("material", "wooden"), ("object", "chair")])
+///     .unwrap();
+/// assert_eq!(prompt, "low poly, a wooden chair, game asset");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PromptTemplate {
+    body: String,
+    prefixes: Vec<String>,
+    suffixes: Vec<String>,
+}
+
+impl PromptTemplate {
+    /// Creates a template from a body containing `{name}` placeholders.
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a prefix placed before the body. Prefixes are kept in insertion order.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Adds a suffix placed after the body. Suffixes are kept in insertion order.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffixes.push(suffix.into());
+        self
+    }
+
+    /// Renders the template with the given variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TripoError::TemplateError` if a placeholder has no matching variable or
+    /// a brace is left unclosed.
+    pub fn render(&self, vars: &[(&str, &str)]) -> Result<String, TripoError> {
+        let body = substitute(&self.body, vars)?;
+        let parts: Vec<&str> = self
+            .prefixes
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(body.as_str()))
+            .chain(self.suffixes.iter().map(String::as_str))
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        Ok(parts.join(", "))
+    }
+}
+
+fn substitute(body: &str, vars: &[(&str, &str)]) -> Result<String, TripoError> {
+    let mut output = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(TripoError::TemplateError(format!(
+                                "unclosed placeholder `{{{}`",
+                                name
+                            )))
+                        }
+                    }
+                }
+                let name = name.trim();
+                let value = vars
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| *value)
+                    .ok_or_else(|| {
+                        TripoError::TemplateError(format!("missing variable `{}`", name))
+                    })?;
+                output.push_str(value);
+            }
+            c => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,232 @@
+//! Client-wide limits on retries and on requests to failing endpoints.
+//!
+//! Per-request retries, such as those of
+//! [`TripoClient::wait_for_task`](crate::TripoClient::wait_for_task), recover from brief
+//! network errors but multiply the load on the API during an outage. A [`RetryBudget`]
+//! caps how many retries the whole client makes relative to its successful requests,
+//! and a [`CircuitBreaker`] stops sending requests to an endpoint that keeps failing,
+//! returning [`TripoError::CircuitOpen`](crate::TripoError::CircuitOpen) until a cool-down
+//! has passed.
+//!
+//! Every client also follows the [`RateLimit`] the API reports in its response headers:
+//! once the remaining quota is spent, submissions and polls wait for the reset instead
+//! of being rejected.
+
+use chrono::Utc;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const LIMIT_HEADER: &str = "X-RateLimit-Limit";
+const REMAINING_HEADER: &str = "X-RateLimit-Remaining";
+const RESET_HEADER: &str = "X-RateLimit-Reset";
+
+/// Reset values above this are Unix timestamps rather than seconds from now.
+const RESET_EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Limits retries across the whole client.
+///
+/// The budget holds up to `max_tokens` tokens and starts full. Every retry spends one
+/// token and every successful API request earns `token_ratio` tokens back, so in the
+/// long run the client retries at most `token_ratio` times per success. When fewer
+/// than one token is left, failed requests are not retried and their error is returned
+/// straight away.
+///
+/// Clones share the same tokens.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    max_tokens: f64,
+    token_ratio: f64,
+    tokens: Arc<Mutex<f64>>,
+}
+
+impl RetryBudget {
+    /// Creates a full budget of `max_tokens` retries that earns `token_ratio` retries
+    /// back per successful request.
+    pub fn new(max_tokens: u32, token_ratio: f64) -> Self {
+        let max_tokens = f64::from(max_tokens);
+        Self {
+            max_tokens,
+            token_ratio: token_ratio.max(0.0),
+            tokens: Arc::new(Mutex::new(max_tokens)),
+        }
+    }
+
+    /// Returns how many retries the budget currently allows.
+    pub fn remaining(&self) -> u32 {
+        *self.tokens.lock().unwrap() as u32
+    }
+
+    /// Spends a token for a retry, returning whether the retry may go ahead.
+    pub(crate) fn try_withdraw(&self) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Earns tokens back for a successful request.
+    pub(crate) fn deposit(&self) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens + self.token_ratio).min(self.max_tokens);
+    }
+}
+
+/// Stops sending requests to an endpoint after repeated failures.
+///
+/// After `failure_threshold` consecutive failures of an endpoint, such as task
+/// submission or task status queries, the breaker opens and requests to that endpoint
+/// fail with [`TripoError::CircuitOpen`](crate::TripoError::CircuitOpen) without being
+/// sent. Once `cool_down` has passed, requests are let through again: a success closes
+/// the breaker, while a failure opens it for another cool-down.
+///
+/// Endpoints are named by their API path, with task IDs written as `{task_id}`: `task`
+/// for submissions and listings, `task/{task_id}` for status queries, `user/balance`
+/// and so on. Network errors and 5xx responses count as failures. Other error responses, like an
+/// invalid request, mean the endpoint is up and count as successes.
+///
+/// Clones share the same state.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cool_down: Duration,
+    endpoints: Arc<Mutex<HashMap<String, EndpointState>>>,
+}
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a breaker that opens after `failure_threshold` consecutive failures and
+    /// stays open for `cool_down`.
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+            endpoints: Arc::default(),
+        }
+    }
+
+    /// Returns whether requests to `endpoint` are currently being refused.
+    pub fn is_open(&self, endpoint: &str) -> bool {
+        self.check(endpoint).is_err()
+    }
+
+    /// Returns `Err` with the remaining cool-down if `endpoint` is open.
+    pub(crate) fn check(&self, endpoint: &str) -> Result<(), Duration> {
+        let endpoints = self.endpoints.lock().unwrap();
+        match endpoints.get(endpoint).and_then(|state| state.open_until) {
+            Some(open_until) => {
+                let now = Instant::now();
+                if now < open_until {
+                    Err(open_until - now)
+                } else {
+                    Ok(())
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Records the outcome of a request to `endpoint`.
+    pub(crate) fn record(&self, endpoint: &str, success: bool) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        if success {
+            endpoints.remove(endpoint);
+            return;
+        }
+        let state = endpoints.entry(endpoint.to_string()).or_default();
+        state.failures += 1;
+        // A failed trial after the cool-down reopens the breaker straight away.
+        if state.failures >= self.failure_threshold || state.open_until.is_some() {
+            state.open_until = Some(Instant::now() + self.cool_down);
+        }
+    }
+}
+
+/// The rate limit reported by the API, from the `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The number of requests allowed per window, if reported.
+    pub limit: Option<u32>,
+    /// The number of requests left in the current window.
+    pub remaining: u32,
+    /// The time until the window resets, as of the response.
+    pub reset: Duration,
+}
+
+impl RateLimit {
+    /// Reads the rate limit from a response. A `429 Too Many Requests` with a
+    /// `Retry-After` header counts as an exhausted limit that resets after the delay.
+    pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap) -> Option<Self> {
+        let saturate = |value: u64| u32::try_from(value).unwrap_or(u32::MAX);
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            if let Some(retry_after) = header(RETRY_AFTER.as_str()) {
+                return Some(RateLimit {
+                    limit: header(LIMIT_HEADER).map(saturate),
+                    remaining: 0,
+                    reset: Duration::from_secs(retry_after),
+                });
+            }
+        }
+        let remaining = header(REMAINING_HEADER)?;
+        let reset = header(RESET_HEADER).unwrap_or_default();
+        let reset = if reset > RESET_EPOCH_THRESHOLD {
+            reset.saturating_sub(Utc::now().timestamp().max(0) as u64)
+        } else {
+            reset
+        };
+        Some(RateLimit {
+            limit: header(LIMIT_HEADER).map(saturate),
+            remaining: saturate(remaining),
+            reset: Duration::from_secs(reset),
+        })
+    }
+}
+
+/// The last rate limit a client observed, shared by its clones.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    last: Mutex<Option<(RateLimit, Instant)>>,
+}
+
+impl RateLimiter {
+    /// Records the rate limit reported by a response, if it reported one.
+    pub(crate) fn observe(&self, status: StatusCode, headers: &HeaderMap) {
+        if let Some(limit) = RateLimit::from_response(status, headers) {
+            *self.last.lock().unwrap() = Some((limit, Instant::now()));
+        }
+    }
+
+    /// Returns the last observed rate limit.
+    pub(crate) fn last(&self) -> Option<RateLimit> {
+        self.last.lock().unwrap().map(|(limit, _)| limit)
+    }
+
+    /// Returns how long to wait before the next request, if the quota is spent.
+    pub(crate) fn delay(&self) -> Option<Duration> {
+        let (limit, observed_at) = (*self.last.lock().unwrap())?;
+        if limit.remaining > 0 {
+            return None;
+        }
+        let wait = observed_at
+            .checked_add(limit.reset)?
+            .saturating_duration_since(Instant::now());
+        (!wait.is_zero()).then_some(wait)
+    }
+}
@@ -0,0 +1,144 @@
+//! Ordering of submissions that wait for a free slot.
+//!
+//! A [`TaskManager`](crate::TaskManager) runs at most its concurrency cap of
+//! submissions at once. Submissions beyond the cap queue up and start in order of
+//! their [`Priority`], oldest first within the same priority, so interactive requests
+//! can overtake bulk work.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// How urgently a queued submission should start.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Background work, such as regenerating a back catalog.
+    Low,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Work someone is waiting for, such as an interactive user request.
+    High,
+}
+
+/// A counting semaphore that hands free slots to the highest-priority waiter.
+pub(crate) struct Scheduler {
+    state: Mutex<State>,
+}
+
+struct State {
+    available: usize,
+    waiters: BinaryHeap<Waiter>,
+    next_seq: u64,
+}
+
+struct Waiter {
+    priority: Priority,
+    seq: u64,
+    wake: oneshot::Sender<()>,
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Higher priority first, then the earlier arrival.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+/// A slot held until dropped.
+pub(crate) struct Permit(Arc<Scheduler>);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+/// Returns a slot that was handed over while its waiter was being dropped.
+struct Waiting<'a> {
+    scheduler: &'a Scheduler,
+    wake: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        if let Some(mut wake) = self.wake.take() {
+            wake.close();
+            if wake.try_recv().is_ok() {
+                self.scheduler.release();
+            }
+        }
+    }
+}
+
+impl Scheduler {
+    /// Creates a scheduler with `slots` free slots.
+    pub(crate) fn new(slots: usize) -> Self {
+        Self {
+            state: Mutex::new(State {
+                available: slots,
+                waiters: BinaryHeap::new(),
+                next_seq: 0,
+            }),
+        }
+    }
+
+    /// Waits for a free slot, overtaking waiters of lower priority.
+    pub(crate) async fn acquire(self: &Arc<Self>, priority: Priority) -> Permit {
+        let wake = {
+            let mut state = self.state.lock().unwrap();
+            if state.available > 0 && state.waiters.is_empty() {
+                state.available -= 1;
+                return Permit(self.clone());
+            }
+            let (sender, receiver) = oneshot::channel();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiters.push(Waiter {
+                priority,
+                seq,
+                wake: sender,
+            });
+            receiver
+        };
+
+        let mut waiting = Waiting {
+            scheduler: self,
+            wake: Some(wake),
+        };
+        if let Some(wake) = &mut waiting.wake {
+            // The sender lives in the queue until a slot is handed over.
+            let _ = wake.await;
+        }
+        waiting.wake = None;
+        Permit(self.clone())
+    }
+
+    /// Hands a slot to the next waiter, or frees it if nobody waits.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        while let Some(waiter) = state.waiters.pop() {
+            if waiter.wake.send(()).is_ok() {
+                return;
+            }
+        }
+        state.available += 1;
+    }
+}
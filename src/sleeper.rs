@@ -0,0 +1,38 @@
+//! The waits between polls and retries.
+//!
+//! Every delay the client takes, such as the pauses of
+//! [`TripoClient::wait_for_task`](crate::TripoClient::wait_for_task) and its retry
+//! backoff, goes through a [`Sleeper`]. Tests can install one with
+//! [`TripoClientBuilder::sleeper`](crate::TripoClientBuilder::sleeper) that returns
+//! immediately or records the requested delays, so polling logic runs without waiting
+//! in real time.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Waits for a duration on behalf of the client.
+pub trait Sleeper: Send + Sync {
+    /// Returns a future that completes after `duration`.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+impl fmt::Debug for dyn Sleeper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sleeper")
+    }
+}
+
+/// The default [`Sleeper`], backed by [`tokio::time::sleep`].
+///
+/// It follows tokio's clock, so it also advances instantly under
+/// [paused time](tokio::time::pause).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
@@ -0,0 +1,127 @@
+//! A local record of the tasks submitted through a [`TaskManager`](crate::TaskManager).
+//!
+//! The Tripo API only knows tasks by their IDs. The [`TaskStore`] keeps the
+//! caller-side context of each submission next to its last known state.
+
+use crate::error::TripoError;
+use crate::types::TaskState;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// The locally recorded context of a submitted task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    /// The ID assigned to the task by the API.
+    pub task_id: String,
+    /// The task type, e.g. "text_to_model".
+    pub task_type: String,
+    /// An optional human-readable name given at submission.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Caller-defined key/value metadata attached at submission.
+    pub metadata: BTreeMap<String, String>,
+    /// When the task was submitted.
+    pub submitted_at: DateTime<Utc>,
+    /// The last observed state of the task, if it has been polled.
+    pub state: Option<TaskState>,
+}
+
+/// A thread-safe, in-memory store of [`TaskRecord`]s keyed by task ID.
+///
+/// Clones share the same underlying records.
+#[derive(Debug, Clone, Default)]
+pub struct TaskStore {
+    records: Arc<RwLock<HashMap<String, TaskRecord>>>,
+}
+
+impl TaskStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a store saved with [`save`](Self::save).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TripoError> {
+        let records: Vec<TaskRecord> = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let store = Self::new();
+        for record in records {
+            store.insert(record);
+        }
+        Ok(store)
+    }
+
+    /// Writes all records to `path` as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TripoError> {
+        fs::write(path, serde_json::to_string_pretty(&self.records())?)?;
+        Ok(())
+    }
+
+    /// Inserts a record, replacing any existing record with the same task ID.
+    pub fn insert(&self, record: TaskRecord) {
+        self.records
+            .write()
+            .unwrap()
+            .insert(record.task_id.clone(), record);
+    }
+
+    /// Returns the record of a task, if it is known.
+    pub fn get(&self, task_id: &str) -> Option<TaskRecord> {
+        self.records.read().unwrap().get(task_id).cloned()
+    }
+
+    /// Records the latest observed state of a task and returns the updated record.
+    pub fn update_state(&self, task_id: &str, state: TaskState) -> Option<TaskRecord> {
+        let mut records = self.records.write().unwrap();
+        let record = records.get_mut(task_id)?;
+        record.state = Some(state);
+        Some(record.clone())
+    }
+
+    /// Returns all records, oldest submission first.
+    pub fn records(&self) -> Vec<TaskRecord> {
+        let mut records: Vec<TaskRecord> = self.records.read().unwrap().values().cloned().collect();
+        records.sort_by(|a, b| {
+            a.submitted_at
+                .cmp(&b.submitted_at)
+                .then_with(|| a.task_id.cmp(&b.task_id))
+        });
+        records
+    }
+
+    /// Returns the records of tasks not yet known to have finished, oldest submission
+    /// first.
+    pub fn unfinished(&self) -> Vec<TaskRecord> {
+        self.records()
+            .into_iter()
+            .filter(|record| !record.state.is_some_and(TaskState::is_final))
+            .collect()
+    }
+
+    /// Returns the most recently submitted record with the given name.
+    pub fn find_by_name(&self, name: &str) -> Option<TaskRecord> {
+        self.records()
+            .into_iter()
+            .rev()
+            .find(|record| record.name.as_deref() == Some(name))
+    }
+
+    /// Returns the records whose metadata maps `key` to `value`, oldest submission first.
+    pub fn find_by_metadata(&self, key: &str, value: &str) -> Vec<TaskRecord> {
+        self.records()
+            .into_iter()
+            .filter(|record| record.metadata.get(key).map(String::as_str) == Some(value))
+            .collect()
+    }
+}
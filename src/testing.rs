@@ -0,0 +1,253 @@
+//! A scripted mock of the Tripo3D API for integration tests.
+//!
+//! Enabled with the `test-support` feature. A [`TripoMockServer`] runs a local
+//! [`wiremock`] server, and each [`Scenario`] mounted on it describes the states a
+//! task passes through on successive polls:
+//!
+//! ```no_run
+//! # use tripo3d::testing::{Scenario, TripoMockServer};
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let server = TripoMockServer::start().await;
+//! let polls = server
+//!     .mount(
+//!         Scenario::task("task_1")
+//!             .pending(2)
+//!             .running(50)
+//!             .success_with_model(std::fs::read("cube.glb")?),
+//!     )
+//!     .await;
+//!
+//! let client = server.client()?;
+//! let status = client.wait_for_task("task_1", false).await?;
+//! assert_eq!(polls.count(), 4);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::{TripoClient, TripoClientBuilder};
+use crate::error::TripoError;
+use crate::sleeper::Sleeper;
+use crate::types::TaskState;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+/// One poll response in a [`Scenario`].
+#[derive(Debug, Clone)]
+enum Step {
+    Status { state: TaskState, progress: u8 },
+    Success { model: Option<Vec<u8>> },
+    HttpError { status: u16 },
+}
+
+/// The sequence of responses a task returns when it is polled.
+///
+/// Each step answers one poll, in order. The last step answers every poll after it,
+/// so a scenario ending in [`success`](Self::success) stays successful.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    task_id: String,
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    /// Starts an empty scenario for `task_id`.
+    pub fn task(task_id: impl Into<String>) -> Self {
+        Scenario {
+            task_id: task_id.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Reports the task as pending for the next `times` polls.
+    pub fn pending(mut self, times: usize) -> Self {
+        for _ in 0..times {
+            self.steps.push(Step::Status {
+                state: TaskState::Pending,
+                progress: 0,
+            });
+        }
+        self
+    }
+
+    /// Reports the task as running at `progress` percent for one poll.
+    pub fn running(mut self, progress: u8) -> Self {
+        self.steps.push(Step::Status {
+            state: TaskState::Running,
+            progress,
+        });
+        self
+    }
+
+    /// Answers one poll with an HTTP error `status`, e.g. 500 for a server fault.
+    pub fn http_error(mut self, status: u16) -> Self {
+        self.steps.push(Step::HttpError { status });
+        self
+    }
+
+    /// Reports the task as failed.
+    pub fn failure(mut self) -> Self {
+        self.steps.push(Step::Status {
+            state: TaskState::Failure,
+            progress: 100,
+        });
+        self
+    }
+
+    /// Reports the task as successful without any result files.
+    pub fn success(mut self) -> Self {
+        self.steps.push(Step::Success { model: None });
+        self
+    }
+
+    /// Reports the task as successful with a PBR model whose download serves `model`.
+    pub fn success_with_model(mut self, model: impl Into<Vec<u8>>) -> Self {
+        self.steps.push(Step::Success {
+            model: Some(model.into()),
+        });
+        self
+    }
+}
+
+/// Counts the polls a mounted [`Scenario`] has answered.
+#[derive(Debug, Clone)]
+pub struct ScenarioPolls(Arc<AtomicUsize>);
+
+impl ScenarioPolls {
+    /// Returns the number of polls answered so far.
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// A local mock of the Tripo3D API that plays back [`Scenario`]s.
+pub struct TripoMockServer {
+    server: MockServer,
+}
+
+impl TripoMockServer {
+    /// Starts a server on a random local port.
+    pub async fn start() -> Self {
+        TripoMockServer {
+            server: MockServer::start().await,
+        }
+    }
+
+    /// Returns the base URL of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns the underlying server, for mounting hand-written mocks next to scenarios.
+    pub fn inner(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Returns a client builder pointed at this server.
+    ///
+    /// Polling delays return immediately, so scenarios play back without waiting.
+    pub fn client_builder(&self) -> TripoClientBuilder {
+        TripoClient::builder()
+            .api_key("test_api_key")
+            .base_url(self.uri())
+            .sleeper(InstantSleeper)
+    }
+
+    /// Builds a client from [`client_builder`](Self::client_builder).
+    pub fn client(&self) -> Result<TripoClient, TripoError> {
+        self.client_builder().build()
+    }
+
+    /// Mounts `scenario` and serves any model files it produces.
+    pub async fn mount(&self, scenario: Scenario) -> ScenarioPolls {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let model_path = format!("/files/{}/model.glb", scenario.task_id);
+
+        for step in &scenario.steps {
+            if let Step::Success { model: Some(model) } = step {
+                Mock::given(method("GET"))
+                    .and(path(model_path.as_str()))
+                    .respond_with(ResponseTemplate::new(200).set_body_bytes(model.clone()))
+                    .mount(&self.server)
+                    .await;
+                break;
+            }
+        }
+
+        Mock::given(method("GET"))
+            .and(path(format!("/task/{}", scenario.task_id)))
+            .respond_with(ScenarioResponder {
+                model_url: format!("{}{}", self.uri(), model_path),
+                scenario,
+                polls: polls.clone(),
+            })
+            .mount(&self.server)
+            .await;
+
+        ScenarioPolls(polls)
+    }
+}
+
+struct ScenarioResponder {
+    scenario: Scenario,
+    model_url: String,
+    polls: Arc<AtomicUsize>,
+}
+
+impl ScenarioResponder {
+    fn task_body(&self, state: TaskState, progress: u8, result: Value) -> Value {
+        json!({
+            "code": 0,
+            "data": {
+                "task_id": self.scenario.task_id,
+                "status": state.as_str(),
+                "progress": progress,
+                "create_time": 0,
+                "result": result,
+            }
+        })
+    }
+}
+
+impl Respond for ScenarioResponder {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        let poll = self.polls.fetch_add(1, Ordering::SeqCst);
+        let steps = &self.scenario.steps;
+        let Some(step) = steps.get(poll).or(steps.last()) else {
+            return ResponseTemplate::new(404);
+        };
+        match step {
+            Step::Status { state, progress } => ResponseTemplate::new(200)
+                .set_body_json(self.task_body(*state, *progress, json!({}))),
+            Step::Success { model } => {
+                let result = match model {
+                    Some(_) => json!({ "pbr_model": { "url": self.model_url } }),
+                    None => json!({}),
+                };
+                ResponseTemplate::new(200).set_body_json(self.task_body(
+                    TaskState::Success,
+                    100,
+                    result,
+                ))
+            }
+            Step::HttpError { status } => ResponseTemplate::new(*status)
+                .set_body_json(json!({ "code": 1000, "message": "scripted error" })),
+        }
+    }
+}
+
+/// A [`Sleeper`] that returns immediately.
+#[derive(Debug, Clone, Copy)]
+struct InstantSleeper;
+
+impl Sleeper for InstantSleeper {
+    fn sleep(&self, _duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async {})
+    }
+}
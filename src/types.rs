@@ -1,15 +1,187 @@
+use crate::animation::{Animation, RigCheckResult, RigType};
+use crate::error::TripoError;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+use url::Url;
 
-/// A private struct for serializing the text-to-model request body.
-#[derive(Serialize)]
-pub(crate) struct TextToModelRequest<'a> {
-    pub(crate) prompt: &'a str,
-    #[serde(rename = "type")]
-    pub(crate) type_: &'a str,
+/// The quality level of the generated texture.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TextureQuality {
+    /// The default texture quality.
+    Standard,
+    /// A higher-resolution, more detailed texture. Costs additional credits.
+    Detailed,
+}
+
+/// The compression applied to a generated model.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    /// Compress the mesh geometry, producing a much smaller file.
+    Geometry,
+}
+
+/// A 3D file format that models can be exported to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OutputFormat {
+    /// glTF, delivered as a binary `.glb` file.
+    Gltf,
+    /// Universal Scene Description, as used by Apple AR Quick Look.
+    Usdz,
+    /// Autodesk FBX.
+    Fbx,
+    /// Wavefront OBJ.
+    Obj,
+    /// Stereolithography, for 3D printing.
+    Stl,
+    /// 3D Manufacturing Format, for 3D printing.
+    #[serde(rename = "3MF")]
+    ThreeMf,
+}
+
+/// The image format of the texture maps of a converted model.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TextureFormat {
+    /// JPEG, the smallest but lossy.
+    Jpeg,
+    /// PNG, lossless.
+    Png,
+    /// WebP, for web viewers.
+    Webp,
+    /// Uncompressed bitmap.
+    Bmp,
+}
+
+/// Where the origin of a converted model is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pivot {
+    /// At the center of the model's bounding box.
+    Center,
+    /// At the center of the bottom face of the bounding box, so the model stands on the
+    /// ground plane when placed at the origin.
+    BottomCenter,
+}
+
+/// The length unit a converted model is expressed in.
+///
+/// Generated models are measured in meters. Engines such as Unreal expect centimeters,
+/// so an FBX exported for them must be scaled up to land at the right size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Meters, as used by Blender, Unity and glTF.
+    Meters,
+    /// Centimeters, as used by Unreal Engine and Maya.
+    Centimeters,
+}
+
+impl Unit {
+    /// The factor that scales a model from meters to this unit.
+    pub fn scale_factor(self) -> f64 {
+        match self {
+            Unit::Meters => 1.0,
+            Unit::Centimeters => 100.0,
+        }
+    }
+}
+
+/// (De)serializes a [`Pivot`] as the API's `pivot_to_center_bottom` flag.
+mod pivot_flag {
+    use super::Pivot;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        pivot: &Option<Pivot>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match pivot {
+            Some(pivot) => serializer.serialize_bool(*pivot == Pivot::BottomCenter),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Pivot>, D::Error> {
+        let bottom = Option::<bool>::deserialize(deserializer)?;
+        Ok(bottom.map(|bottom| {
+            if bottom {
+                Pivot::BottomCenter
+            } else {
+                Pivot::Center
+            }
+        }))
+    }
+}
+
+/// The file format of a rigged model, with its skeleton and skin.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum RigFormat {
+    /// Binary glTF, for web viewers and Godot.
+    Glb,
+    /// Autodesk FBX, for Unity and Unreal Engine.
+    Fbx,
+}
+
+/// The orientation strategy for an image-derived model.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Orientation {
+    /// Keep the model in the canonical orientation chosen by the generator.
+    Default,
+    /// Rotate the model so its up-axis and facing match the subject in the input image.
+    AlignImage,
+}
+
+/// A request to create a text-to-model task.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TextToModelRequest {
+    /// A text description of the 3D model to generate.
+    pub prompt: String,
+    /// The model version to use, e.g. "v2.5-20250123". The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    /// Whether to generate a texture for the model. The server default is `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture: Option<bool>,
+    /// The quality of the generated texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_quality: Option<TextureQuality>,
+    /// The maximum number of faces of the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_limit: Option<u32>,
+    /// The compression applied to the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<Compression>,
+    /// A style applied to the generated model, e.g. "object:clay".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// The seed of the geometry generation. The same prompt and seed produce the same
+    /// model, while different seeds produce variations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_seed: Option<u32>,
+}
+
+impl TextToModelRequest {
+    /// Creates a request for the given prompt with all options left at their server defaults.
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
 }
 
 /// Represents an object stored in an S3-compatible service.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct S3Object {
     /// The name of the S3 bucket.
     pub bucket: String,
@@ -23,7 +195,7 @@ pub struct S3Object {
 /// 1. As an object in an S3 bucket (`object`).
 /// 2. As a publicly accessible URL (`url`).
 /// 3. As a token representing a previously uploaded file (`file_token`).
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct FileContent {
     /// The file format, e.g., "png", "jpeg".
     #[serde(rename = "type")]
@@ -40,17 +212,689 @@ pub struct FileContent {
 }
 
 /// A request to create an image-to-model task.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ImageTaskRequest {
-    /// The type of the task, which should be "image_to_model".
-    #[serde(rename = "type")]
-    pub type_: &'static str,
     /// The file content to be used for the task.
     pub file: FileContent,
+    /// The model version to use, e.g. "v2.5-20250123". The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    /// Whether to generate a texture for the model. The server default is `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture: Option<bool>,
+    /// The quality of the generated texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_quality: Option<TextureQuality>,
+    /// The maximum number of faces of the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_limit: Option<u32>,
+    /// The compression applied to the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<Compression>,
+    /// A style applied to the generated model, e.g. "object:clay".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// How to orient the generated model. The server default is `Orientation::Default`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<Orientation>,
+    /// Whether to scale the model to its estimated real-world size, in meters.
+    ///
+    /// Useful for AR and e-commerce viewers that place models at physical scale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_size: Option<bool>,
+    /// Guidance for texturing that is applied on top of the geometry derived from the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_prompt: Option<TexturePrompt>,
+}
+
+/// Guidance that steers how a model is textured, independently of its geometry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TexturePrompt {
+    /// A text description of the desired texture, e.g. "weathered bronze".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// A reference image whose materials and look are transferred onto the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style_image: Option<FileContent>,
+}
+
+impl TexturePrompt {
+    /// Creates a texture prompt from a text description.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a texture prompt from a style reference image.
+    pub fn style_image(style_image: FileContent) -> Self {
+        Self {
+            style_image: Some(style_image),
+            ..Default::default()
+        }
+    }
+}
+
+/// A request to create a multiview-to-model task from photos of one object taken from
+/// several sides.
+///
+/// Only the front view is required. Missing views are sent as empty objects, which is
+/// how the API expects them.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MultiviewRequest {
+    /// The views in the order the API expects: front, left, back and right.
+    #[serde(with = "view_files")]
+    pub files: [Option<FileContent>; 4],
+    /// The model version to use, e.g. "v2.5-20250123". The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    /// Whether to generate a texture for the model. The server default is `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture: Option<bool>,
+    /// The quality of the generated texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_quality: Option<TextureQuality>,
+    /// The maximum number of faces of the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_limit: Option<u32>,
+    /// The compression applied to the generated model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<Compression>,
+    /// A style applied to the generated model, e.g. "object:clay".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    /// How to orient the generated model. The server default is `Orientation::Default`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<Orientation>,
+}
+
+impl MultiviewRequest {
+    /// Creates a request from the front view, with the other views missing.
+    pub fn new(front: FileContent) -> Self {
+        Self {
+            files: [Some(front), None, None, None],
+            ..Default::default()
+        }
+    }
+
+    /// Sets the left view.
+    pub fn left(mut self, file: FileContent) -> Self {
+        self.files[1] = Some(file);
+        self
+    }
+
+    /// Sets the back view.
+    pub fn back(mut self, file: FileContent) -> Self {
+        self.files[2] = Some(file);
+        self
+    }
+
+    /// Sets the right view.
+    pub fn right(mut self, file: FileContent) -> Self {
+        self.files[3] = Some(file);
+        self
+    }
+}
+
+/// (De)serializes the views of a [`MultiviewRequest`], writing missing views as `{}`.
+mod view_files {
+    use super::FileContent;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum View {
+        File(FileContent),
+        Missing {},
+    }
+
+    pub(super) fn serialize<S: Serializer>(
+        files: &[Option<FileContent>; 4],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(files.iter().map(|file| match file {
+            Some(file) => View::File(file.clone()),
+            None => View::Missing {},
+        }))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[Option<FileContent>; 4], D::Error> {
+        let views = Vec::<View>::deserialize(deserializer)?;
+        let count = views.len();
+        let files: Vec<Option<FileContent>> = views
+            .into_iter()
+            .map(|view| match view {
+                View::File(file) => Some(file),
+                View::Missing {} => None,
+            })
+            .collect();
+        files
+            .try_into()
+            .map_err(|_| serde::de::Error::invalid_length(count, &"4 views"))
+    }
+}
+
+/// A request to rebuild the topology of a previously generated model.
+///
+/// The result is a cleaner, lower-density mesh that is better suited to animation
+/// and real-time rendering.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RetopologyRequest {
+    /// The ID of the completed task whose model should be remeshed.
+    pub original_model_task_id: String,
+    /// The target number of faces of the remeshed model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_limit: Option<u32>,
+    /// Whether to produce quad faces instead of triangles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quad: Option<bool>,
+}
+
+impl RetopologyRequest {
+    /// Creates a request to remesh the model of the given task with server defaults.
+    pub fn new(original_model_task_id: impl Into<String>) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A request to check whether the model of a previously generated task can be rigged.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RigCheckRequest {
+    /// The ID of the completed task whose model should be checked.
+    pub original_model_task_id: String,
+}
+
+impl RigCheckRequest {
+    /// Creates a request to check the model of the given task.
+    pub fn new(original_model_task_id: impl Into<String>) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+        }
+    }
+}
+
+/// A request to rig the model of a previously generated task with a skeleton and skin.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RigRequest {
+    /// The ID of the completed task whose model should be rigged.
+    pub original_model_task_id: String,
+    /// The file format of the rigged model. The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_format: Option<RigFormat>,
+}
+
+impl RigRequest {
+    /// Creates a request to rig the model of the given task with server defaults.
+    pub fn new(original_model_task_id: impl Into<String>) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+            out_format: None,
+        }
+    }
+}
+
+/// A request to retarget a preset animation onto a rigged model.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RetargetRequest {
+    /// The ID of the completed rigging task whose model should be animated.
+    pub original_model_task_id: String,
+    /// The animation to apply.
+    pub animation: Animation,
+    /// The file format of the animated model. The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_format: Option<RigFormat>,
+}
+
+impl RetargetRequest {
+    /// Creates a request to apply `animation` to the model of the given rigging task.
+    pub fn new(original_model_task_id: impl Into<String>, animation: Animation) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+            animation,
+            out_format: None,
+        }
+    }
+}
+
+/// The brick sizes accepted for [`Stylization::Lego`].
+pub const BLOCK_SIZE_RANGE: RangeInclusive<u32> = 32..=128;
+
+/// The resolutions accepted for [`Stylization::Voxel`].
+pub const VOXEL_RESOLUTION_RANGE: RangeInclusive<u32> = 16..=256;
+
+/// A style that [`StylizeRequest`] rebuilds a model in, with the parameters that
+/// style accepts.
+///
+/// Parameters left at `None` use the server default. Submitting a request checks them
+/// with [`validate`](Self::validate) first.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "style", rename_all = "lowercase")]
+pub enum Stylization {
+    /// Built from toy bricks.
+    Lego {
+        /// The size of a brick.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_size: Option<u32>,
+    },
+    /// Built from cubes on a regular grid.
+    Voxel {
+        /// The number of voxels along the longest side of the model.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        resolution: Option<u32>,
+    },
+    /// Built from blocks in the look of Minecraft.
+    Minecraft,
+    /// Broken into Voronoi cells.
+    Voronoi {
+        /// The size of a cell, relative to the model.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cell_size: Option<f64>,
+    },
+}
+
+impl Stylization {
+    /// Checks that the block size or resolution is within [`BLOCK_SIZE_RANGE`] or
+    /// [`VOXEL_RESOLUTION_RANGE`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TripoError::InvalidParameter` describing the problem.
+    pub fn validate(&self) -> Result<(), TripoError> {
+        let (name, value, range) = match *self {
+            Stylization::Lego {
+                block_size: Some(block_size),
+            } => ("block_size", block_size, BLOCK_SIZE_RANGE),
+            Stylization::Voxel {
+                resolution: Some(resolution),
+            } => ("resolution", resolution, VOXEL_RESOLUTION_RANGE),
+            _ => return Ok(()),
+        };
+        if !range.contains(&value) {
+            return Err(TripoError::InvalidParameter(format!(
+                "{} is {}, it must be between {} and {}",
+                name,
+                value,
+                range.start(),
+                range.end()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A request to rebuild the model of a previously generated task in a [`Stylization`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StylizeRequest {
+    /// The ID of the completed task whose model should be stylized.
+    pub original_model_task_id: String,
+    /// The style and its parameters.
+    #[serde(flatten)]
+    pub style: Stylization,
+}
+
+impl StylizeRequest {
+    /// Creates a request to rebuild the model of the given task in `style`.
+    pub fn new(original_model_task_id: impl Into<String>, style: Stylization) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+            style,
+        }
+    }
+}
+
+/// A request to refine a draft model into a high-quality one.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefineRequest {
+    /// The ID of the completed draft task whose model should be refined.
+    pub draft_model_task_id: String,
+}
+
+impl RefineRequest {
+    /// Creates a request to refine the model of the given draft task.
+    pub fn new(draft_model_task_id: impl Into<String>) -> Self {
+        Self {
+            draft_model_task_id: draft_model_task_id.into(),
+        }
+    }
+}
+
+/// A request to convert the model of a previously generated task into another format.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConvertModelRequest {
+    /// The ID of the completed task whose model should be converted.
+    pub original_model_task_id: String,
+    /// The format to convert the model to.
+    pub format: OutputFormat,
+    /// The width and height of the texture maps, in pixels, e.g. 1024, 2048 or 4096.
+    /// The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_size: Option<u32>,
+    /// The image format of the texture maps. The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_format: Option<TextureFormat>,
+    /// Where to place the model's origin. The server default is used if `None`.
+    #[serde(
+        rename = "pivot_to_center_bottom",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "pivot_flag"
+    )]
+    pub pivot: Option<Pivot>,
+    /// The factor the model is scaled by. The server default is used if `None`.
+    ///
+    /// See [`unit`](Self::unit) for converting between length units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale_factor: Option<f64>,
+    /// Whether to bake the PBR materials into a single diffuse texture, for renderers
+    /// without PBR support. The server default is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bake: Option<bool>,
+}
+
+impl ConvertModelRequest {
+    /// Creates a request to convert the model of the given task with server defaults.
+    pub fn new(original_model_task_id: impl Into<String>, format: OutputFormat) -> Self {
+        Self {
+            original_model_task_id: original_model_task_id.into(),
+            format,
+            texture_size: None,
+            texture_format: None,
+            pivot: None,
+            scale_factor: None,
+            bake: None,
+        }
+    }
+
+    /// Scales the model from meters to `unit`, replacing any previous scale factor.
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.scale_factor = Some(unit.scale_factor());
+        self
+    }
+
+    /// Creates a request to convert the model of the given task with `options`.
+    pub fn with_options(
+        original_model_task_id: impl Into<String>,
+        format: OutputFormat,
+        options: ConvertOptions,
+    ) -> Self {
+        Self {
+            texture_size: options.texture_size,
+            texture_format: options.texture_format,
+            pivot: options.pivot,
+            scale_factor: options.scale_factor,
+            bake: options.bake,
+            ..Self::new(original_model_task_id, format)
+        }
+    }
+}
+
+/// Conversion options for [`TripoClient::generate_as`](crate::TripoClient::generate_as),
+/// mirroring those of a [`ConvertModelRequest`]. Each option left `None` uses the
+/// server default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConvertOptions {
+    /// The width and height of the texture maps, in pixels.
+    pub texture_size: Option<u32>,
+    /// The image format of the texture maps.
+    pub texture_format: Option<TextureFormat>,
+    /// Where to place the model's origin.
+    pub pivot: Option<Pivot>,
+    /// The factor the model is scaled by.
+    pub scale_factor: Option<f64>,
+    /// Whether to bake the PBR materials into a single diffuse texture.
+    pub bake: Option<bool>,
+}
+
+impl ConvertOptions {
+    /// Creates options that use the server defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the width and height of the texture maps, in pixels.
+    pub fn texture_size(mut self, texture_size: u32) -> Self {
+        self.texture_size = Some(texture_size);
+        self
+    }
+
+    /// Sets the image format of the texture maps.
+    pub fn texture_format(mut self, texture_format: TextureFormat) -> Self {
+        self.texture_format = Some(texture_format);
+        self
+    }
+
+    /// Sets where to place the model's origin.
+    pub fn pivot(mut self, pivot: Pivot) -> Self {
+        self.pivot = Some(pivot);
+        self
+    }
+
+    /// Scales the model from meters to `unit`.
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.scale_factor = Some(unit.scale_factor());
+        self
+    }
+
+    /// Sets whether to bake the PBR materials into a single diffuse texture.
+    pub fn bake(mut self, bake: bool) -> Self {
+        self.bake = Some(bake);
+        self
+    }
+}
+
+/// Options for [`TripoClient::generate_with`](crate::TripoClient::generate_with).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GenerateOptions {
+    /// The file format the model should be delivered in. If set, the generated model is
+    /// converted into it; otherwise it is left as generated.
+    pub target_format: Option<OutputFormat>,
+    /// The options for the conversion into the target format.
+    pub convert: ConvertOptions,
+}
+
+impl GenerateOptions {
+    /// Creates options that leave the model as generated.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the file format the model should be delivered in.
+    pub fn target_format(mut self, format: OutputFormat) -> Self {
+        self.target_format = Some(format);
+        self
+    }
+
+    /// Sets the options for the conversion into the target format.
+    pub fn convert(mut self, convert: ConvertOptions) -> Self {
+        self.convert = convert;
+        self
+    }
+}
+
+/// Generation options applied to every task submitted by a client.
+///
+/// Set them with [`TripoClientBuilder::defaults`](crate::TripoClientBuilder::defaults)
+/// or in the `[defaults]` section of `tripo.toml`. Each default only fills in an
+/// option the request leaves unset.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaskDefaults {
+    /// The default model version.
+    pub model_version: Option<String>,
+    /// The default texture quality.
+    pub texture_quality: Option<TextureQuality>,
+    /// The default maximum number of faces.
+    pub face_limit: Option<u32>,
+    /// The default style.
+    pub style: Option<String>,
+}
+
+impl TaskDefaults {
+    /// Fills in the unset generation options of `request` with these defaults.
+    ///
+    /// Requests that are not generation tasks are left unchanged.
+    pub fn apply(&self, request: &mut TaskRequest) {
+        macro_rules! fill {
+            ($request:expr) => {{
+                let request = $request;
+                if request.model_version.is_none() {
+                    request.model_version = self.model_version.clone();
+                }
+                if request.texture_quality.is_none() {
+                    request.texture_quality = self.texture_quality;
+                }
+                if request.face_limit.is_none() {
+                    request.face_limit = self.face_limit;
+                }
+                if request.style.is_none() {
+                    request.style = self.style.clone();
+                }
+            }};
+        }
+
+        match request {
+            TaskRequest::TextToModel(r) => fill!(r),
+            TaskRequest::ImageToModel(r) => fill!(r),
+            TaskRequest::MultiviewToModel(r) => fill!(r),
+            TaskRequest::Retopology(_)
+            | TaskRequest::Refine(_)
+            | TaskRequest::ConvertModel(_)
+            | TaskRequest::RigCheck(_)
+            | TaskRequest::Rig(_)
+            | TaskRequest::Retarget(_)
+            | TaskRequest::Stylize(_) => {}
+        }
+    }
+}
+
+/// A task submission for the `task` endpoint.
+///
+/// Each variant is serialized with its `type` field set to the matching task type.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)] // Requests are built once per submission; boxing would only hurt ergonomics.
+pub enum TaskRequest {
+    /// A text-to-model generation task.
+    TextToModel(TextToModelRequest),
+    /// An image-to-model generation task.
+    ImageToModel(ImageTaskRequest),
+    /// A multiview-to-model generation task.
+    MultiviewToModel(MultiviewRequest),
+    /// A retopology task on a previously generated model.
+    #[serde(rename = "highpoly_to_lowpoly")]
+    Retopology(RetopologyRequest),
+    /// A refinement of a draft model.
+    #[serde(rename = "refine_model")]
+    Refine(RefineRequest),
+    /// A format conversion of a previously generated model.
+    ConvertModel(ConvertModelRequest),
+    /// A previously generated model rebuilt in a style.
+    #[serde(rename = "stylize_model")]
+    Stylize(StylizeRequest),
+    /// A check whether a model can be rigged.
+    #[serde(rename = "animate_prerigcheck")]
+    RigCheck(RigCheckRequest),
+    /// Rigging of a model with a skeleton and skin.
+    #[serde(rename = "animate_rig")]
+    Rig(RigRequest),
+    /// A preset animation retargeted onto a rigged model.
+    #[serde(rename = "animate_retarget")]
+    Retarget(RetargetRequest),
+}
+
+impl TaskRequest {
+    /// Returns the API task type string, e.g. "text_to_model".
+    pub fn task_type(&self) -> &'static str {
+        match self {
+            TaskRequest::TextToModel(_) => "text_to_model",
+            TaskRequest::ImageToModel(_) => "image_to_model",
+            TaskRequest::MultiviewToModel(_) => "multiview_to_model",
+            TaskRequest::Retopology(_) => "highpoly_to_lowpoly",
+            TaskRequest::Refine(_) => "refine_model",
+            TaskRequest::ConvertModel(_) => "convert_model",
+            TaskRequest::Stylize(_) => "stylize_model",
+            TaskRequest::RigCheck(_) => "animate_prerigcheck",
+            TaskRequest::Rig(_) => "animate_rig",
+            TaskRequest::Retarget(_) => "animate_retarget",
+        }
+    }
+}
+
+impl From<TextToModelRequest> for TaskRequest {
+    fn from(request: TextToModelRequest) -> Self {
+        TaskRequest::TextToModel(request)
+    }
+}
+
+impl From<ImageTaskRequest> for TaskRequest {
+    fn from(request: ImageTaskRequest) -> Self {
+        TaskRequest::ImageToModel(request)
+    }
+}
+
+impl From<MultiviewRequest> for TaskRequest {
+    fn from(request: MultiviewRequest) -> Self {
+        TaskRequest::MultiviewToModel(request)
+    }
+}
+
+impl From<RetopologyRequest> for TaskRequest {
+    fn from(request: RetopologyRequest) -> Self {
+        TaskRequest::Retopology(request)
+    }
+}
+
+impl From<RefineRequest> for TaskRequest {
+    fn from(request: RefineRequest) -> Self {
+        TaskRequest::Refine(request)
+    }
+}
+
+impl From<ConvertModelRequest> for TaskRequest {
+    fn from(request: ConvertModelRequest) -> Self {
+        TaskRequest::ConvertModel(request)
+    }
+}
+
+impl From<StylizeRequest> for TaskRequest {
+    fn from(request: StylizeRequest) -> Self {
+        TaskRequest::Stylize(request)
+    }
+}
+
+impl From<RigCheckRequest> for TaskRequest {
+    fn from(request: RigCheckRequest) -> Self {
+        TaskRequest::RigCheck(request)
+    }
+}
+
+impl From<RigRequest> for TaskRequest {
+    fn from(request: RigRequest) -> Self {
+        TaskRequest::Rig(request)
+    }
+}
+
+impl From<RetargetRequest> for TaskRequest {
+    fn from(request: RetargetRequest) -> Self {
+        TaskRequest::Retarget(request)
+    }
+}
+
+/// The predicted credit cost of a task.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CreditEstimate {
+    /// The number of credits the task is expected to consume.
+    pub credits: f64,
 }
 
 /// The response from an API call that successfully initiates a task.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct TaskResponse {
     /// The unique identifier for the newly created task.
     #[serde(rename = "task_id")]
@@ -74,8 +918,12 @@ pub(crate) struct StandardUploadData {
 }
 
 /// Represents the lifecycle state of a generation task.
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+///
+/// The API may report new states, so matches on it need a wildcard arm; use
+/// [`TaskState::is_final`] to tell whether a task has stopped.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum TaskState {
     /// The task has been submitted but has not yet started processing.
     Pending,
@@ -85,6 +933,151 @@ pub enum TaskState {
     Success,
     /// The task failed to complete.
     Failure,
+    /// The task was refused by content moderation.
+    Banned,
+}
+
+impl TaskState {
+    /// Whether the task has stopped and its state will not change again.
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            TaskState::Success | TaskState::Failure | TaskState::Banned
+        )
+    }
+
+    /// The state's name as the API spells it, e.g. `"running"`.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            TaskState::Pending => "pending",
+            TaskState::Running => "running",
+            TaskState::Success => "success",
+            TaskState::Failure => "failure",
+            TaskState::Banned => "banned",
+        }
+    }
+}
+
+/// The kind of artifact a task produced, used to pick which files to read or download.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    /// The primary textured model, in PBR format.
+    PbrModel,
+    /// An alternative model in GLB format.
+    GlbModel,
+    /// The untextured base mesh.
+    BaseModel,
+    /// The model produced by a post-processing task, such as a retopologized mesh.
+    Model,
+    /// The model produced by rigging or retargeting an animation.
+    RiggedModel,
+    /// The file produced by a format conversion.
+    ConvertedFile,
+    /// A rendered preview image.
+    RenderedImage,
+    /// A rendered turntable video.
+    RenderedVideo,
+    /// The albedo (base color) texture map.
+    AlbedoMap,
+    /// The normal texture map.
+    NormalMap,
+    /// The combined metallic-roughness texture map.
+    MetallicRoughnessMap,
+}
+
+impl AssetKind {
+    /// Every asset kind.
+    pub const ALL: &'static [AssetKind] = &[
+        AssetKind::PbrModel,
+        AssetKind::GlbModel,
+        AssetKind::BaseModel,
+        AssetKind::Model,
+        AssetKind::RiggedModel,
+        AssetKind::ConvertedFile,
+        AssetKind::RenderedImage,
+        AssetKind::RenderedVideo,
+        AssetKind::AlbedoMap,
+        AssetKind::NormalMap,
+        AssetKind::MetallicRoughnessMap,
+    ];
+
+    /// The kind's name, e.g. `"pbr_model"`, also used for cache subdirectories.
+    pub fn name(self) -> &'static str {
+        match self {
+            AssetKind::PbrModel => "pbr_model",
+            AssetKind::GlbModel => "glb_model",
+            AssetKind::BaseModel => "base_model",
+            AssetKind::Model => "model",
+            AssetKind::RiggedModel => "rigged_model",
+            AssetKind::ConvertedFile => "converted_file",
+            AssetKind::RenderedImage => "rendered_image",
+            AssetKind::RenderedVideo => "rendered_video",
+            AssetKind::AlbedoMap => "albedo_map",
+            AssetKind::NormalMap => "normal_map",
+            AssetKind::MetallicRoughnessMap => "metallic_roughness_map",
+        }
+    }
+
+    /// Whether the asset is a 3D model rather than an image, video or texture map.
+    pub fn is_model(self) -> bool {
+        !matches!(self, AssetKind::RenderedImage | AssetKind::RenderedVideo)
+            && !self.is_texture_map()
+    }
+
+    /// Whether the asset is one of the model's individual PBR texture maps.
+    pub fn is_texture_map(self) -> bool {
+        matches!(
+            self,
+            AssetKind::AlbedoMap | AssetKind::NormalMap | AssetKind::MetallicRoughnessMap
+        )
+    }
+}
+
+/// Selects which assets a download downloads, see
+/// [`TripoClient::download_assets`](crate::TripoClient::download_assets).
+///
+/// An empty filter matches every [model](AssetKind::is_model); images, videos and
+/// texture maps are only downloaded when their kind is added.
+///
+/// # Examples
+///
+/// ```
+/// use tripo3d::{AssetFilter, AssetKind};
+///
+/// let models = AssetFilter::new();
+/// assert!(models.matches(AssetKind::PbrModel));
+/// assert!(!models.matches(AssetKind::RenderedImage));
+///
+/// let previews = AssetFilter::new().kind(AssetKind::RenderedImage);
+/// assert!(!previews.matches(AssetKind::PbrModel));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AssetFilter {
+    /// The asset kinds to download.
+    pub kinds: HashSet<AssetKind>,
+}
+
+impl AssetFilter {
+    /// Creates a filter that matches every model.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an asset kind to download.
+    pub fn kind(mut self, kind: AssetKind) -> Self {
+        self.kinds.insert(kind);
+        self
+    }
+
+    /// Returns whether assets of `kind` pass the filter.
+    pub fn matches(&self, kind: AssetKind) -> bool {
+        if self.kinds.is_empty() {
+            kind.is_model()
+        } else {
+            self.kinds.contains(&kind)
+        }
+    }
 }
 
 /// A downloadable file asset, typically a 3D model.
@@ -103,13 +1096,109 @@ pub struct TaskResult {
     /// An alternative model output in GLB format.
     #[serde(default)]
     pub glb_model: Option<ResultFile>,
+    /// The untextured base mesh, when the task produced one.
+    #[serde(default)]
+    pub base_model: Option<ResultFile>,
+    /// The model produced by a post-processing task, such as a retopologized mesh. Rigged
+    /// and converted models are reported here too.
+    #[serde(default)]
+    pub model: Option<ResultFile>,
+    /// A rendered turntable video of the model, when the task produced one.
+    #[serde(default)]
+    pub rendered_video: Option<ResultFile>,
+    /// A rendered preview image of the model. Some responses report it under
+    /// [`TaskOutput::generated_image`] instead; [`TaskStatus::rendered_image`] checks
+    /// both.
+    #[serde(default)]
+    pub rendered_image: Option<ResultFile>,
+    /// The albedo (base color) texture map of the model, when reported separately.
+    #[serde(default)]
+    pub albedo_map: Option<ResultFile>,
+    /// The normal texture map of the model, when reported separately.
+    #[serde(default)]
+    pub normal_map: Option<ResultFile>,
+    /// The combined metallic-roughness texture map of the model, when reported
+    /// separately.
+    #[serde(default)]
+    pub metallic_roughness_map: Option<ResultFile>,
 }
 
-/// A preview image generated during the task.
+impl TaskResult {
+    /// Returns the available files with the kind of the field they came from.
+    ///
+    /// The `model` field is reported as [`AssetKind::Model`], since the result alone does
+    /// not say which task produced it; [`TaskStatus::assets`] tells rigged and converted
+    /// models apart.
+    pub fn files(&self) -> Vec<(AssetKind, &ResultFile)> {
+        [
+            (AssetKind::PbrModel, &self.pbr_model),
+            (AssetKind::GlbModel, &self.glb_model),
+            (AssetKind::BaseModel, &self.base_model),
+            (AssetKind::Model, &self.model),
+            (AssetKind::RenderedVideo, &self.rendered_video),
+            (AssetKind::RenderedImage, &self.rendered_image),
+            (AssetKind::AlbedoMap, &self.albedo_map),
+            (AssetKind::NormalMap, &self.normal_map),
+            (
+                AssetKind::MetallicRoughnessMap,
+                &self.metallic_roughness_map,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(kind, file)| file.as_ref().map(|file| (kind, file)))
+        .collect()
+    }
+
+    /// Returns the file of the given kind, if the result has one.
+    ///
+    /// [`AssetKind::RiggedModel`] and [`AssetKind::ConvertedFile`] both read the `model`
+    /// field.
+    pub fn file(&self, kind: AssetKind) -> Option<&ResultFile> {
+        match kind {
+            AssetKind::PbrModel => self.pbr_model.as_ref(),
+            AssetKind::GlbModel => self.glb_model.as_ref(),
+            AssetKind::BaseModel => self.base_model.as_ref(),
+            AssetKind::Model | AssetKind::RiggedModel | AssetKind::ConvertedFile => {
+                self.model.as_ref()
+            }
+            AssetKind::RenderedImage => self.rendered_image.as_ref(),
+            AssetKind::RenderedVideo => self.rendered_video.as_ref(),
+            AssetKind::AlbedoMap => self.albedo_map.as_ref(),
+            AssetKind::NormalMap => self.normal_map.as_ref(),
+            AssetKind::MetallicRoughnessMap => self.metallic_roughness_map.as_ref(),
+        }
+    }
+}
+
+/// Additional outputs reported for a task, such as a preview image.
 #[derive(Debug, Deserialize, Clone)]
 pub struct TaskOutput {
     /// The URL of the generated preview image.
     pub generated_image: Option<String>,
+    /// The orientation of the generated model, reported when auto-orientation was requested.
+    #[serde(default)]
+    pub orientation: Option<ModelOrientation>,
+    /// Whether the model can be rigged, reported by rig checks.
+    #[serde(default)]
+    pub riggable: Option<bool>,
+    /// The skeleton topology detected by a rig check.
+    #[serde(default)]
+    pub rig_type: Option<RigType>,
+    /// Why the model cannot be rigged, reported by rig checks.
+    #[serde(default)]
+    pub reasons: Vec<String>,
+}
+
+/// How the axes of a generated model map onto world directions.
+///
+/// Importers can use this to apply the right transform, e.g. when converting a Y-up
+/// model for a Z-up engine.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct ModelOrientation {
+    /// The axis pointing up, e.g. "+y".
+    pub up_axis: String,
+    /// The axis the model faces, e.g. "+z".
+    pub front_axis: String,
 }
 
 /// The detailed status and data of a generation task.
@@ -117,6 +1206,12 @@ pub struct TaskOutput {
 pub struct TaskStatus {
     /// The unique identifier of the task.
     pub task_id: String,
+    /// The task type, e.g. "text_to_model", if reported.
+    #[serde(rename = "type", default)]
+    pub task_type: Option<String>,
+    /// The prompt the task was submitted with, if reported.
+    #[serde(default)]
+    pub prompt: Option<String>,
     /// The current lifecycle state of the task.
     pub status: TaskState,
     /// The completion progress of the task, from 0 to 100.
@@ -127,10 +1222,248 @@ pub struct TaskStatus {
     pub result: TaskResult,
     /// A link to a generated preview image, if available.
     pub output: Option<TaskOutput>,
+    /// The credits the task consumed, if reported.
+    #[serde(default)]
+    pub consumed_credit: Option<f64>,
+}
+
+impl TaskStatus {
+    /// Returns every asset of the task with its kind.
+    ///
+    /// Unlike [`TaskResult::files`], the `model` field is classified by the task type,
+    /// as a [`AssetKind::RiggedModel`] or [`AssetKind::ConvertedFile`] where it applies,
+    /// and a preview image reported as the output's generated image is included.
+    pub fn assets(&self) -> Vec<(AssetKind, ResultFile)> {
+        let model_kind = match self.task_type.as_deref() {
+            Some("animate_rig" | "animate_retarget") => AssetKind::RiggedModel,
+            Some("convert_model") => AssetKind::ConvertedFile,
+            _ => AssetKind::Model,
+        };
+        let mut assets: Vec<_> = self
+            .result
+            .files()
+            .into_iter()
+            .filter(|(kind, _)| *kind != AssetKind::RenderedImage)
+            .map(|(kind, file)| match kind {
+                AssetKind::Model => (model_kind, file.clone()),
+                kind => (kind, file.clone()),
+            })
+            .collect();
+        if let Some(image) = self.rendered_image() {
+            assets.push((AssetKind::RenderedImage, image));
+        }
+        assets
+    }
+
+    /// Returns the rendered preview image, whether the API reported it in the result
+    /// or as the output's generated image.
+    pub fn rendered_image(&self) -> Option<ResultFile> {
+        self.result.rendered_image.clone().or_else(|| {
+            let url = self.output.as_ref()?.generated_image.clone()?;
+            Some(ResultFile { url })
+        })
+    }
+
+    /// Returns the outcome of a finished rig check task, see
+    /// [`TripoClient::check_rig`](crate::TripoClient::check_rig).
+    pub fn rig_check(&self) -> Option<RigCheckResult> {
+        let output = self.output.as_ref()?;
+        Some(RigCheckResult {
+            riggable: output.riggable?,
+            topology: output.rig_type,
+            reasons: output.reasons.clone(),
+        })
+    }
+}
+
+/// Selects which task updates a watch stream yields.
+///
+/// Each non-empty criterion must match; an empty filter matches every update.
+///
+/// # Examples
+///
+/// ```
+/// use tripo3d::{TaskState, WatchFilter};
+///
+/// let filter = WatchFilter::new()
+///     .state(TaskState::Success)
+///     .state(TaskState::Failure)
+///     .task_type("text_to_model");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WatchFilter {
+    /// The states to yield.
+    pub states: HashSet<TaskState>,
+    /// The task types to yield. Updates without a reported type never match.
+    pub task_types: HashSet<String>,
+    /// The task IDs to yield.
+    pub task_ids: HashSet<String>,
+}
+
+impl WatchFilter {
+    /// Creates a filter that matches every update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a state to yield.
+    pub fn state(mut self, state: TaskState) -> Self {
+        self.states.insert(state);
+        self
+    }
+
+    /// Adds a task type to yield.
+    pub fn task_type(mut self, task_type: impl Into<String>) -> Self {
+        self.task_types.insert(task_type.into());
+        self
+    }
+
+    /// Adds a task ID to yield.
+    pub fn task_id(mut self, task_id: impl Into<String>) -> Self {
+        self.task_ids.insert(task_id.into());
+        self
+    }
+
+    /// Returns whether `status` passes the filter.
+    pub fn matches(&self, status: &TaskStatus) -> bool {
+        let state_matches = self.states.is_empty() || self.states.contains(&status.status);
+        let type_matches = self.task_types.is_empty()
+            || status
+                .task_type
+                .as_ref()
+                .is_some_and(|task_type| self.task_types.contains(task_type));
+        let id_matches = self.task_ids.is_empty() || self.task_ids.contains(&status.task_id);
+        state_matches && type_matches && id_matches
+    }
+}
+
+/// Selects tasks for [`TripoClient::list_tasks`](crate::TripoClient::list_tasks).
+///
+/// States, task types and the creation window are sent to the server as query
+/// parameters. Every criterion is also checked locally, so the prompt filter and servers
+/// that ignore a parameter still give exact results. An empty filter matches every task.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use tripo3d::{TaskFilter, TaskState};
+///
+/// let filter = TaskFilter::new()
+///     .state(TaskState::Failure)
+///     .created_after(Utc::now() - Duration::days(1))
+///     .prompt_contains("dragon");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    /// The states to list.
+    pub states: HashSet<TaskState>,
+    /// The task types to list. Tasks without a reported type never match.
+    pub task_types: HashSet<String>,
+    /// Lists only tasks created at or after this time.
+    pub created_after: Option<DateTime<Utc>>,
+    /// Lists only tasks created before this time.
+    pub created_before: Option<DateTime<Utc>>,
+    /// Lists only tasks whose prompt contains this text, ignoring case.
+    pub prompt_contains: Option<String>,
+}
+
+impl TaskFilter {
+    /// Creates a filter that matches every task.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a state to list.
+    pub fn state(mut self, state: TaskState) -> Self {
+        self.states.insert(state);
+        self
+    }
+
+    /// Adds a task type to list.
+    pub fn task_type(mut self, task_type: impl Into<String>) -> Self {
+        self.task_types.insert(task_type.into());
+        self
+    }
+
+    /// Lists only tasks created at or after `time`.
+    pub fn created_after(mut self, time: DateTime<Utc>) -> Self {
+        self.created_after = Some(time);
+        self
+    }
+
+    /// Lists only tasks created before `time`.
+    pub fn created_before(mut self, time: DateTime<Utc>) -> Self {
+        self.created_before = Some(time);
+        self
+    }
+
+    /// Lists only tasks whose prompt contains `text`, ignoring case.
+    pub fn prompt_contains(mut self, text: impl Into<String>) -> Self {
+        self.prompt_contains = Some(text.into());
+        self
+    }
+
+    /// Returns whether `status` passes the filter.
+    pub fn matches(&self, status: &TaskStatus) -> bool {
+        let state_matches = self.states.is_empty() || self.states.contains(&status.status);
+        let type_matches = self.task_types.is_empty()
+            || status
+                .task_type
+                .as_ref()
+                .is_some_and(|task_type| self.task_types.contains(task_type));
+        let created = status.create_time as i64;
+        let after_matches = self
+            .created_after
+            .is_none_or(|time| created >= time.timestamp());
+        let before_matches = self
+            .created_before
+            .is_none_or(|time| created < time.timestamp());
+        let prompt_matches = self.prompt_contains.as_ref().is_none_or(|text| {
+            status
+                .prompt
+                .as_ref()
+                .is_some_and(|prompt| prompt.to_lowercase().contains(&text.to_lowercase()))
+        });
+        state_matches && type_matches && after_matches && before_matches && prompt_matches
+    }
+
+    /// The query parameters for the criteria the server filters on.
+    pub(crate) fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if !self.states.is_empty() {
+            let mut states: Vec<_> = self.states.iter().map(|state| state.as_str()).collect();
+            states.sort_unstable();
+            query.push(("status", states.join(",")));
+        }
+        if !self.task_types.is_empty() {
+            let mut task_types: Vec<_> = self.task_types.iter().map(String::as_str).collect();
+            task_types.sort_unstable();
+            query.push(("type", task_types.join(",")));
+        }
+        if let Some(time) = self.created_after {
+            query.push(("start_time", time.timestamp().to_string()));
+        }
+        if let Some(time) = self.created_before {
+            query.push(("end_time", time.timestamp().to_string()));
+        }
+        query
+    }
+}
+
+/// A page of tasks returned by [`TripoClient::list_tasks`](crate::TripoClient::list_tasks).
+#[derive(Deserialize, Debug, Clone)]
+pub struct TaskList {
+    /// The tasks on this page that match the filter.
+    #[serde(default)]
+    pub tasks: Vec<TaskStatus>,
+    /// The total number of tasks the server matched across all pages.
+    #[serde(default)]
+    pub total: u64,
 }
 
 /// The user's account balance.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Balance {
     /// The available, usable balance.
     pub balance: f64,
@@ -138,8 +1471,259 @@ pub struct Balance {
     pub frozen: f64,
 }
 
+/// The user the API key belongs to, as returned by
+/// [`TripoClient::get_account`](crate::TripoClient::get_account).
+#[derive(Deserialize, Debug, Clone)]
+pub struct Account {
+    /// The user's unique identifier.
+    pub user_id: String,
+    /// The user's display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The user's email address.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// The subscription plan, e.g. `"free"` or `"pro"`.
+    #[serde(default)]
+    pub plan: Option<String>,
+    /// The organization the key belongs to, for organization keys.
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    /// The limits that apply under the plan.
+    #[serde(default)]
+    pub limits: AccountLimits,
+}
+
+/// An organization that owns an API key.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Organization {
+    /// The organization's unique identifier.
+    pub id: String,
+    /// The organization's name.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Plan limits of an [`Account`]. A limit the API does not report is `None`.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AccountLimits {
+    /// How many tasks may run at the same time.
+    #[serde(default)]
+    pub concurrent_tasks: Option<u32>,
+    /// How many API requests may be made per minute.
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+}
+
+/// How often [`TripoClient::wait_for_task`](crate::TripoClient::wait_for_task) polls.
+///
+/// Queued tasks are polled slowly, running tasks faster as they near completion, and
+/// transient network errors are retried with exponential backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollSchedule {
+    /// The delay between polls while the task is pending.
+    pub pending: Duration,
+    /// The delay between polls while the task is running.
+    pub running: Duration,
+    /// The delay between polls once progress reaches [`finishing_progress`](Self::finishing_progress).
+    pub finishing: Duration,
+    /// The progress from which the `finishing` delay is used.
+    pub finishing_progress: u8,
+    /// The delay before the first retry after a network error. It doubles on every
+    /// consecutive error.
+    pub error_backoff: Duration,
+    /// The longest delay between retries after network errors.
+    pub max_error_backoff: Duration,
+    /// How many consecutive network errors are retried before giving up.
+    pub max_errors: u32,
+}
+
+impl Default for PollSchedule {
+    fn default() -> Self {
+        Self {
+            pending: Duration::from_secs(5),
+            running: Duration::from_secs(2),
+            finishing: Duration::from_secs(1),
+            finishing_progress: 80,
+            error_backoff: Duration::from_secs(1),
+            max_error_backoff: Duration::from_secs(30),
+            max_errors: 5,
+        }
+    }
+}
+
+impl PollSchedule {
+    /// Returns the delay before polling a task again after observing `status`.
+    pub fn delay_for(&self, status: &TaskStatus) -> Duration {
+        match status.status {
+            TaskState::Pending => self.pending,
+            _ if status.progress >= self.finishing_progress => self.finishing,
+            _ => self.running,
+        }
+    }
+
+    /// Returns the delay before retrying after `errors` consecutive network errors.
+    pub fn backoff_for(&self, errors: u32) -> Duration {
+        let factor = 2u32.saturating_pow(errors.saturating_sub(1));
+        self.error_backoff
+            .saturating_mul(factor)
+            .min(self.max_error_backoff)
+    }
+}
+
+/// The Tripo deployment a client talks to.
+///
+/// The environment sets the REST and WebSocket base URLs, unless they are set
+/// explicitly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Environment {
+    /// The public API.
+    #[default]
+    Production,
+    /// A self-hosted or otherwise custom deployment at the given base URL.
+    Custom(String),
+}
+
+impl Environment {
+    /// Returns the REST base URL of the environment.
+    pub fn base_url(&self) -> &str {
+        match self {
+            Environment::Production => "https://api.tripo3d.ai/v2/openapi/",
+            Environment::Custom(url) => url,
+        }
+    }
+
+    /// Parses `production` or a custom base URL, as in the
+    /// `TRIPO_ENVIRONMENT` environment variable.
+    pub(crate) fn parse(value: &str) -> Self {
+        match value {
+            "production" => Environment::Production,
+            url => Environment::Custom(url.to_string()),
+        }
+    }
+}
+
+/// How downloaded model files are named.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileNaming {
+    /// The last segment of the download URL, e.g. `model.glb`.
+    #[default]
+    UrlSegment,
+    /// The last URL segment with a short hash of the URL path inserted before the
+    /// extension, e.g. `model-1a2b3c4d.glb`, so results of different tasks that share a
+    /// file name cannot overwrite each other. The query string is ignored, so the name is
+    /// stable across re-signed URLs.
+    UrlHash,
+}
+
+impl FileNaming {
+    /// Returns the file name for a download from `url`.
+    pub fn file_name(&self, url: &Url) -> String {
+        let segment = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .unwrap_or("downloaded_model.bin");
+
+        match self {
+            FileNaming::UrlSegment => segment.to_string(),
+            FileNaming::UrlHash => {
+                let digest = Sha256::digest(format!(
+                    "{}{}",
+                    url.origin().ascii_serialization(),
+                    url.path()
+                ));
+                let hash: String = digest[..4]
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect();
+                match segment.rsplit_once('.') {
+                    Some((stem, extension)) if !stem.is_empty() => {
+                        format!("{}-{}.{}", stem, hash, extension)
+                    }
+                    _ => format!("{}-{}", segment, hash),
+                }
+            }
+        }
+    }
+}
+
+/// Progress of a file upload, reported by
+/// [`TripoClient::upload_file_with_progress`](crate::TripoClient::upload_file_with_progress).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadEvent {
+    /// The number of bytes read from the file and handed to the connection so far.
+    pub bytes_sent: u64,
+    /// The size of the file in bytes.
+    pub total: u64,
+}
+
+/// Progress of a model download, reported by
+/// [`TripoClient::download_model_with_progress`](crate::TripoClient::download_model_with_progress).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadEvent {
+    /// The number of bytes written to disk so far.
+    pub bytes_done: u64,
+    /// The size of the file in bytes, if the server reported it.
+    pub total: Option<u64>,
+    /// The path the file is being written to.
+    pub path: PathBuf,
+}
+
+/// A time window used to scope history queries.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeRange {
+    /// The inclusive start of the window.
+    pub start: DateTime<Utc>,
+    /// The exclusive end of the window.
+    pub end: DateTime<Utc>,
+}
+
+/// Page selection for paginated endpoints.
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    /// The page number to fetch, starting at 1.
+    pub page: u32,
+    /// The maximum number of records per page.
+    pub page_size: u32,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
+
+/// A single credit consumption entry.
+#[derive(Debug, Deserialize, Clone)]
+pub struct UsageRecord {
+    /// The task that consumed the credits.
+    pub task_id: String,
+    /// The type of the task, e.g. "text_to_model".
+    #[serde(rename = "type")]
+    pub task_type: String,
+    /// The number of credits charged for the task.
+    pub credits: f64,
+    /// The Unix timestamp of when the credits were charged.
+    pub create_time: u64,
+}
+
+/// A page of credit usage history.
+#[derive(Debug, Deserialize, Clone)]
+pub struct UsageHistory {
+    /// The usage records on this page.
+    #[serde(default)]
+    pub records: Vec<UsageRecord>,
+    /// The total number of records matching the query across all pages.
+    #[serde(default)]
+    pub total: u64,
+}
+
 /// (Internal) A generic wrapper for API responses where the content is nested under a "data" field.
 #[derive(Debug, Deserialize)]
 pub(crate) struct ApiResponse<T> {
     pub(crate) data: T,
-} 
\ No newline at end of file
+}
@@ -0,0 +1,368 @@
+//! Resumable multi-step pipelines.
+//!
+//! A [`Workflow`] chains the steps that take a prompt or image to a finished asset:
+//! generate, then optionally refine, rig, animate and convert, and finally download.
+//! Each step works on the model of the step before it.
+//!
+//! [`Workflow::run`] records every task it submits and every step it completes in a
+//! state file before moving on. Running the same workflow again with the same file
+//! after a crash skips the completed steps and waits for a submitted task instead of
+//! submitting it again, so nothing is generated twice. The file also keeps a
+//! [fingerprint](Workflow::fingerprint) of the workflow's requests, so it is never
+//! resumed by a workflow that would submit different tasks.
+
+use crate::animation::Animation;
+use crate::client::TripoClient;
+use crate::error::TripoError;
+use crate::types::{
+    AssetFilter, ConvertModelRequest, ConvertOptions, OutputFormat, RefineRequest, RetargetRequest,
+    RigFormat, RigRequest, TaskRequest, TaskState, TaskStatus,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+/// A step of a [`Workflow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowStep {
+    /// The generation task.
+    Generate,
+    /// The refinement of the generated draft.
+    Refine,
+    /// Rigging of the model.
+    Rig,
+    /// A preset animation retargeted onto the rigged model.
+    Animate,
+    /// A format conversion of the model.
+    Convert,
+    /// The download of the final model.
+    Download,
+}
+
+/// A pipeline from a generation request to a downloaded asset.
+///
+/// # Example
+///
+/// ```no_run
+/// # use tripo3d::{Animation, OutputFormat, TextToModelRequest, TripoClient, Workflow};
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let client = TripoClient::new(Some("your_api_key".to_string()))?;
+/// let workflow = Workflow::new(TextToModelRequest::new("a knight"))
+///     .animate(Animation::Walk)
+///     .target_format(OutputFormat::Fbx)
+///     .download("knight");
+/// let state = workflow.run(&client, "knight.workflow.json").await?;
+/// println!("downloaded {:?}", state.files);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Workflow {
+    /// The generation request the workflow starts with.
+    pub request: TaskRequest,
+    /// Whether to refine the generated model, which must be a draft.
+    pub refine: bool,
+    /// Whether to rig the model. Implied by `animation`.
+    pub rig: bool,
+    /// The file format of the rigged and animated models. The server default is used
+    /// if `None`.
+    pub rig_format: Option<RigFormat>,
+    /// The preset animation to apply to the rigged model, if any.
+    pub animation: Option<Animation>,
+    /// The format to convert the final model to, if any.
+    pub target_format: Option<OutputFormat>,
+    /// The export settings of the conversion.
+    pub convert: ConvertOptions,
+    /// The directory to download the final model into, if any.
+    pub dest_dir: Option<PathBuf>,
+    /// The assets of the final task to download. Matches every model by default.
+    pub filter: AssetFilter,
+}
+
+impl Workflow {
+    /// Creates a workflow that only runs `request`.
+    pub fn new(request: impl Into<TaskRequest>) -> Self {
+        Self {
+            request: request.into(),
+            refine: false,
+            rig: false,
+            rig_format: None,
+            animation: None,
+            target_format: None,
+            convert: ConvertOptions::default(),
+            dest_dir: None,
+            filter: AssetFilter::default(),
+        }
+    }
+
+    /// Refines the generated draft.
+    pub fn refine(mut self) -> Self {
+        self.refine = true;
+        self
+    }
+
+    /// Rigs the model in `format`.
+    pub fn rig(mut self, format: RigFormat) -> Self {
+        self.rig = true;
+        self.rig_format = Some(format);
+        self
+    }
+
+    /// Rigs the model and applies `animation` to it.
+    pub fn animate(mut self, animation: Animation) -> Self {
+        self.rig = true;
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Converts the final model to `format`.
+    pub fn target_format(mut self, format: OutputFormat) -> Self {
+        self.target_format = Some(format);
+        self
+    }
+
+    /// Sets the export settings of the conversion.
+    pub fn convert(mut self, convert: ConvertOptions) -> Self {
+        self.convert = convert;
+        self
+    }
+
+    /// Downloads the final model into `dest_dir`.
+    pub fn download(mut self, dest_dir: impl Into<PathBuf>) -> Self {
+        self.dest_dir = Some(dest_dir.into());
+        self
+    }
+
+    /// Sets which assets of the final task to download.
+    pub fn filter(mut self, filter: AssetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Returns the steps the workflow runs, in order.
+    pub fn steps(&self) -> Vec<WorkflowStep> {
+        let mut steps = vec![WorkflowStep::Generate];
+        if self.refine {
+            steps.push(WorkflowStep::Refine);
+        }
+        if self.rig || self.animation.is_some() {
+            steps.push(WorkflowStep::Rig);
+        }
+        if self.animation.is_some() {
+            steps.push(WorkflowStep::Animate);
+        }
+        if self.target_format.is_some() {
+            steps.push(WorkflowStep::Convert);
+        }
+        if self.dest_dir.is_some() {
+            steps.push(WorkflowStep::Download);
+        }
+        steps
+    }
+
+    /// Returns a fingerprint of the tasks the workflow submits, which is saved in the
+    /// state file and must match for [`run`](Self::run) to resume from it.
+    pub fn fingerprint(&self) -> String {
+        let requests: Vec<TaskRequest> = self
+            .steps()
+            .into_iter()
+            .filter(|&step| step != WorkflowStep::Download)
+            .map(|step| self.request_for(step, ""))
+            .collect();
+        let canonical =
+            serde_json::to_string(&requests).expect("task requests always serialize to JSON");
+        format!("{:x}", Sha256::digest(canonical.as_bytes()))
+    }
+
+    /// Runs the workflow, resuming from the state saved in `state_path` if there is
+    /// one.
+    ///
+    /// Returns the final state, which is also left in `state_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TripoError::ConfigError` if `state_path` holds the state of a workflow
+    /// with other steps or requests, `TripoError::TaskFailed` if a task fails,
+    /// `TripoError::ContentRejected` if content moderation refuses one,
+    /// `TripoError::Unrecorded` with the task's ID if a task was submitted but the state
+    /// file could not be written, or another `TripoError` if a request or the state file
+    /// fails. A failed task is recorded, so running the workflow again fails the same
+    /// way; remove the state file to start over.
+    pub async fn run(
+        &self,
+        client: &TripoClient,
+        state_path: impl AsRef<Path>,
+    ) -> Result<WorkflowState, TripoError> {
+        let state_path = state_path.as_ref();
+        let fingerprint = self.fingerprint();
+        let mut state = match WorkflowState::load(state_path).await? {
+            Some(state) if state.fingerprint != fingerprint => {
+                return Err(TripoError::ConfigError(format!(
+                    "{} holds the state of a different workflow",
+                    state_path.display()
+                )))
+            }
+            Some(state) => state,
+            None => WorkflowState::new(&self.steps(), fingerprint),
+        };
+
+        let mut previous: Option<TaskStatus> = None;
+        for index in 0..state.steps.len() {
+            let step = state.steps[index].step;
+            if step == WorkflowStep::Download {
+                let status = previous.as_ref().expect("download follows a task step");
+                if !state.steps[index].done {
+                    let dest_dir = self.dest_dir.as_deref().unwrap_or(Path::new("."));
+                    state.files = client
+                        .download_assets(status, dest_dir, &self.filter)
+                        .await?;
+                    state.steps[index].done = true;
+                    state.save(state_path).await?;
+                }
+                continue;
+            }
+
+            let task_id = match &state.steps[index].task_id {
+                Some(task_id) => task_id.clone(),
+                None => {
+                    let original_task_id = previous.as_ref().map_or("", |p| p.task_id.as_str());
+                    let request = self.request_for(step, original_task_id);
+                    let response = client.submit_task(&request).await?;
+                    tracing::debug!("workflow step {:?} submitted {}", step, response.task_id);
+                    state.steps[index].task_id = Some(response.task_id.clone());
+                    if let Err(e) = state.save(state_path).await {
+                        return Err(TripoError::Unrecorded {
+                            task_id: response.task_id,
+                            source: Box::new(e),
+                        });
+                    }
+                    response.task_id
+                }
+            };
+            let status = client.wait_for_task(&task_id, false).await?;
+            if status.status != TaskState::Success {
+                return Err(TripoError::from_failed_task(&status));
+            }
+            if !state.steps[index].done {
+                state.steps[index].done = true;
+                state.save(state_path).await?;
+            }
+            previous = Some(status);
+        }
+        Ok(state)
+    }
+
+    /// Builds the request of a task step on the model of the task `original_task_id`.
+    fn request_for(&self, step: WorkflowStep, original_task_id: &str) -> TaskRequest {
+        match step {
+            WorkflowStep::Generate => self.request.clone(),
+            WorkflowStep::Refine => RefineRequest::new(original_task_id).into(),
+            WorkflowStep::Rig => RigRequest {
+                out_format: self.rig_format,
+                ..RigRequest::new(original_task_id)
+            }
+            .into(),
+            WorkflowStep::Animate => RetargetRequest {
+                out_format: self.rig_format,
+                ..RetargetRequest::new(
+                    original_task_id,
+                    self.animation.expect("animate step has an animation"),
+                )
+            }
+            .into(),
+            WorkflowStep::Convert => ConvertModelRequest::with_options(
+                original_task_id,
+                self.target_format.expect("convert step has a format"),
+                self.convert.clone(),
+            )
+            .into(),
+            WorkflowStep::Download => unreachable!("download is not a task step"),
+        }
+    }
+}
+
+/// The progress of one step of a workflow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepState {
+    /// The step.
+    pub step: WorkflowStep,
+    /// The ID of the task submitted for the step, once there is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    /// Whether the step has completed.
+    pub done: bool,
+}
+
+/// The persisted progress of a [`Workflow`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowState {
+    /// The [fingerprint](Workflow::fingerprint) of the workflow the state belongs to.
+    pub fingerprint: String,
+    /// The steps of the workflow, in order.
+    pub steps: Vec<StepState>,
+    /// The downloaded files, once the download step has completed.
+    #[serde(default)]
+    pub files: Vec<PathBuf>,
+}
+
+impl WorkflowState {
+    fn new(steps: &[WorkflowStep], fingerprint: String) -> Self {
+        Self {
+            fingerprint,
+            steps: steps
+                .iter()
+                .map(|&step| StepState {
+                    step,
+                    task_id: None,
+                    done: false,
+                })
+                .collect(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Reads the state saved at `path`, or `None` if there is no file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TripoError` if the file cannot be read or parsed.
+    pub async fn load(path: impl AsRef<Path>) -> Result<Option<Self>, TripoError> {
+        match fs::read_to_string(path).await {
+            Ok(contents) => Ok(Some(serde_json::from_str(&contents)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the ID of the task submitted for `step`, if there is one.
+    pub fn task_id(&self, step: WorkflowStep) -> Option<&str> {
+        self.steps
+            .iter()
+            .find(|state| state.step == step)
+            .and_then(|state| state.task_id.as_deref())
+    }
+
+    /// Returns whether every step has completed.
+    pub fn is_finished(&self) -> bool {
+        self.steps.iter().all(|state| state.done)
+    }
+
+    /// Writes the state next to `path` and moves it into place, so a crash mid-write
+    /// leaves the previous state intact.
+    async fn save(&self, path: &Path) -> Result<(), TripoError> {
+        let mut staging = path.as_os_str().to_owned();
+        staging.push(".tmp");
+        let staging = PathBuf::from(staging);
+        let mut file = fs::File::create(&staging).await?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())
+            .await?;
+        file.sync_data().await?;
+        fs::rename(&staging, path).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,356 @@
+use serde_json::json;
+use std::collections::HashSet;
+use tripo3d::{
+    AnimateOptions, Animation, RigCheckResult, RigFormat, RigType, TaskState, TaskStatus,
+    TripoClient, TripoError,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_list_animations_covers_the_catalog() {
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), "http://localhost").unwrap();
+    let animations = client.list_animations();
+    assert!(animations.contains(&Animation::Walk));
+    assert!(animations.contains(&Animation::QuadrupedWalk));
+
+    let names: HashSet<&str> = animations
+        .iter()
+        .map(|animation| animation.name())
+        .collect();
+    assert_eq!(names.len(), animations.len());
+    assert!(names.iter().all(|name| name.starts_with("preset:")));
+}
+
+#[test]
+fn test_animation_names_round_trip() {
+    for &animation in Animation::ALL {
+        assert_eq!(Animation::from_name(animation.name()), Some(animation));
+        let json = serde_json::to_value(animation).unwrap();
+        assert_eq!(json, animation.name());
+        assert_eq!(
+            serde_json::from_value::<Animation>(json).unwrap(),
+            animation
+        );
+    }
+    assert_eq!(Animation::from_name("preset:moonwalk"), None);
+}
+
+#[tokio::test]
+async fn test_retarget_animations_groups_clips() {
+    let server = MockServer::start().await;
+
+    for (animation, task_id) in [
+        ("preset:idle", "idle_task"),
+        ("preset:quadruped:walk", "walk_task"),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("task"))
+            .and(body_json(json!({
+                "type": "animate_retarget",
+                "original_model_task_id": "rig_task",
+                "animation": animation
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "task_id": task_id }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("task/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "task_id": task_id,
+                    "status": "success",
+                    "progress": 100,
+                    "create_time": 1678886400,
+                    "result": {
+                        "model": { "url": format!("{}/{task_id}.fbx", server.uri()) }
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("{task_id}.fbx")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy clip data"))
+            .mount(&server)
+            .await;
+    }
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let clips = client
+        .retarget_animations("rig_task", &[Animation::Idle, Animation::QuadrupedWalk])
+        .await;
+    assert!(clips.failed().is_empty());
+    assert_eq!(
+        clips.clips(),
+        [
+            (Animation::Idle, "idle_task".to_string()),
+            (Animation::QuadrupedWalk, "walk_task".to_string()),
+        ]
+    );
+
+    let statuses = clips.wait().await.unwrap();
+    assert!(statuses
+        .iter()
+        .all(|(_, status)| status.status == TaskState::Success));
+
+    let dir = tempfile::tempdir().unwrap();
+    let files = clips.download(dir.path()).await.unwrap();
+    assert_eq!(files.len(), 2);
+    assert!(files[0].1[0].starts_with(dir.path().join("idle")));
+    assert!(files[1].1[0].starts_with(dir.path().join("quadruped_walk")));
+}
+
+#[tokio::test]
+async fn test_retarget_animations_reports_failed_submissions() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "animate_retarget",
+            "original_model_task_id": "rig_task",
+            "animation": "preset:idle"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "idle_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "animate_retarget",
+            "original_model_task_id": "rig_task",
+            "animation": "preset:walk"
+        })))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "code": 1004,
+            "message": "invalid parameter"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let clips = client
+        .retarget_animations("rig_task", &[Animation::Idle, Animation::Walk])
+        .await;
+    assert_eq!(clips.clips(), [(Animation::Idle, "idle_task".to_string())]);
+    assert_eq!(clips.failed().len(), 1);
+    assert!(matches!(
+        clips.failed()[0],
+        (Animation::Walk, TripoError::ApiError { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_check_rig_returns_typed_result() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "animate_prerigcheck",
+            "original_model_task_id": "model_task"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "check_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/check_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "check_task",
+                "type": "animate_prerigcheck",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {},
+                "output": { "riggable": true, "rig_type": "quadruped" }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let check = client.check_rig("model_task").await.unwrap();
+    assert_eq!(
+        check,
+        RigCheckResult {
+            riggable: true,
+            topology: Some(RigType::Quadruped),
+            reasons: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn test_rig_check_tolerates_unknown_topology() {
+    let status: TaskStatus = serde_json::from_value(json!({
+        "task_id": "check_task",
+        "status": "success",
+        "progress": 100,
+        "create_time": 1678886400,
+        "result": {},
+        "output": {
+            "riggable": false,
+            "rig_type": "centipede",
+            "reasons": ["too many limbs"]
+        }
+    }))
+    .unwrap();
+
+    let check = status.rig_check().unwrap();
+    assert!(!check.riggable);
+    assert_eq!(check.topology, Some(RigType::Unknown));
+    assert_eq!(check.reasons, ["too many limbs"]);
+}
+
+#[tokio::test]
+async fn test_rig_model_with_fbx_output() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "animate_rig",
+            "original_model_task_id": "model_task",
+            "out_format": "fbx"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "rig_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let response = client
+        .rig_model("model_task", RigFormat::Fbx)
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "rig_task");
+}
+
+async fn mount_task(
+    server: &MockServer,
+    request: serde_json::Value,
+    task_id: &str,
+    output: serde_json::Value,
+) {
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(request))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": task_id }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("task/{task_id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": task_id,
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "model": { "url": format!("https://example.com/{task_id}.fbx") }
+                },
+                "output": output
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_animate_model_chains_check_rig_and_retarget() {
+    let server = MockServer::start().await;
+
+    mount_task(
+        &server,
+        json!({ "type": "animate_prerigcheck", "original_model_task_id": "model_task" }),
+        "check_task",
+        json!({ "riggable": true, "rig_type": "biped" }),
+    )
+    .await;
+    mount_task(
+        &server,
+        json!({ "type": "animate_rig", "original_model_task_id": "model_task", "out_format": "fbx" }),
+        "rig_task",
+        json!({}),
+    )
+    .await;
+    mount_task(
+        &server,
+        json!({
+            "type": "animate_retarget",
+            "original_model_task_id": "rig_task",
+            "animation": "preset:walk",
+            "out_format": "fbx"
+        }),
+        "walk_task",
+        json!({}),
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let status = client
+        .animate_model(
+            "model_task",
+            Animation::Walk,
+            AnimateOptions::new().format(RigFormat::Fbx),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status.task_id, "walk_task");
+    assert_eq!(
+        status.result.model.unwrap().url,
+        "https://example.com/walk_task.fbx"
+    );
+}
+
+#[tokio::test]
+async fn test_animate_model_stops_when_not_riggable() {
+    let server = MockServer::start().await;
+
+    mount_task(
+        &server,
+        json!({ "type": "animate_prerigcheck", "original_model_task_id": "model_task" }),
+        "check_task",
+        json!({ "riggable": false, "reasons": ["no limbs found"] }),
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let err = client
+        .animate_model("model_task", Animation::Walk, AnimateOptions::new())
+        .await
+        .unwrap_err();
+    match err {
+        TripoError::NotRiggable { task_id, reasons } => {
+            assert_eq!(task_id, "model_task");
+            assert_eq!(reasons, ["no limbs found"]);
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
@@ -0,0 +1,108 @@
+use serde_json::json;
+use tripo3d::{
+    ApiErrorCode, ConvertOptions, ErrorCategory, OutputFormat, TextToModelRequest, TripoClient,
+    TripoError,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_api_error_carries_categorized_code() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "code": 2010,
+            "message": "You need more credits to start a new task."
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let err = client.text_to_model("a small cat").await.unwrap_err();
+    let code = err.api_error_code().unwrap();
+
+    assert_eq!(code, ApiErrorCode(2010));
+    assert_eq!(code.category(), ErrorCategory::Quota);
+    assert!(code.suggestion().unwrap().contains("credits"));
+}
+
+#[tokio::test]
+async fn test_moderation_code_is_content_rejected() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "code": 2008,
+            "message": "The prompt violates the content policy."
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let err = client.text_to_model("a small cat").await.unwrap_err();
+    assert!(matches!(
+        err,
+        TripoError::ContentRejected { reason } if reason == "The prompt violates the content policy."
+    ));
+}
+
+#[tokio::test]
+async fn test_banned_task_is_content_rejected() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "banned_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/banned_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "banned_task",
+                "status": "banned",
+                "progress": 0,
+                "create_time": 1752091365,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let err = client
+        .generate_as(
+            OutputFormat::Fbx,
+            TextToModelRequest::new("a small cat"),
+            ConvertOptions::new(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TripoError::ContentRejected { reason } if reason.contains("banned_task")
+    ));
+}
+
+#[test]
+fn test_api_error_code_categories() {
+    assert_eq!(ApiErrorCode(1002).category(), ErrorCategory::Auth);
+    assert_eq!(ApiErrorCode(2004).category(), ErrorCategory::InvalidInput);
+    assert_eq!(ApiErrorCode(2008).category(), ErrorCategory::Moderation);
+    assert_eq!(ApiErrorCode(1000).category(), ErrorCategory::Server);
+    assert_eq!(ApiErrorCode(9999).category(), ErrorCategory::Unknown);
+    assert_eq!(ApiErrorCode(9999).suggestion(), None);
+}
@@ -0,0 +1,76 @@
+use serde_json::json;
+use tripo3d::{TaskState, TextToModelRequest, TripoClient};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_generate_candidates_per_seed() {
+    let server = MockServer::start().await;
+
+    for (seed, status) in [(1, "success"), (2, "failure")] {
+        let task_id = format!("task_seed_{seed}");
+        Mock::given(method("POST"))
+            .and(path("task"))
+            .and(body_json(json!({
+                "type": "text_to_model",
+                "prompt": "a chair",
+                "model_seed": seed
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "task_id": task_id }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("task/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "task_id": task_id,
+                    "status": status,
+                    "progress": 100,
+                    "create_time": 1678886400,
+                    "result": {},
+                    "output": {
+                        "generated_image": format!("{}/preview_{seed}.webp", server.uri())
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("preview_1.webp"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy preview"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let candidates = client
+        .generate_candidates(&TextToModelRequest::new("a chair"), [1, 2, 3], dir.path())
+        .await;
+
+    assert_eq!(candidates.len(), 3);
+    let (seed, candidate) = &candidates[0];
+    let candidate = candidate.as_ref().unwrap();
+    assert_eq!((*seed, candidate.seed), (1, 1));
+    assert_eq!(candidate.status.status, TaskState::Success);
+    let preview = candidate.preview.as_ref().unwrap();
+    assert!(preview.starts_with(dir.path().join("seed-1")));
+    assert_eq!(std::fs::read(preview).unwrap(), b"dummy preview");
+
+    let (seed, candidate) = &candidates[1];
+    let candidate = candidate.as_ref().unwrap();
+    assert_eq!(*seed, 2);
+    assert_eq!(candidate.status.status, TaskState::Failure);
+    assert!(candidate.preview.is_none());
+
+    // Seed 3 has no mocked submission, so only its own entry fails.
+    assert_eq!(candidates[2].0, 3);
+    assert!(candidates[2].1.is_err());
+}
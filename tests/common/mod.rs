@@ -0,0 +1,25 @@
+//! Fixtures shared by the integration tests.
+
+// Every test binary compiles this module but uses only some of it.
+#![allow(dead_code)]
+
+/// Returns a minimal binary glTF file whose JSON chunk holds `json`, padded with spaces
+/// to a multiple of four bytes.
+pub fn glb_with_json(json: &str) -> Vec<u8> {
+    let mut chunk = json.as_bytes().to_vec();
+    chunk.resize(chunk.len().div_ceil(4) * 4, b' ');
+    let length = (12 + 8 + chunk.len()) as u32;
+
+    let mut bytes = b"glTF".to_vec();
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    bytes.extend_from_slice(&length.to_le_bytes());
+    bytes.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(b"JSON");
+    bytes.extend_from_slice(&chunk);
+    bytes
+}
+
+/// Returns a minimal valid binary glTF file.
+pub fn glb_bytes() -> Vec<u8> {
+    glb_with_json("{}")
+}
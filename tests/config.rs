@@ -0,0 +1,37 @@
+use std::fs;
+use std::path::Path;
+use tripo3d::{TripoConfig, TripoError};
+
+#[test]
+fn test_config_from_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let key_path = dir.path().join("api_key");
+    fs::write(&key_path, "file_api_key\n").unwrap();
+
+    let config_path = dir.path().join("tripo.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "api_key_file = {:?}\nbase_url = \"http://localhost:1234/\"\ntimeout_secs = 30\ndownload_dir = \"models\"\n",
+            key_path.to_str().unwrap()
+        ),
+    )
+    .unwrap();
+
+    let config = TripoConfig::from_path(&config_path).unwrap();
+    assert_eq!(config.base_url.as_deref(), Some("http://localhost:1234/"));
+    assert_eq!(config.timeout_secs, Some(30));
+
+    let client = config.into_builder().unwrap().build().unwrap();
+    assert_eq!(client.download_dir(), Some(Path::new("models")));
+}
+
+#[test]
+fn test_config_rejects_unknown_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("tripo.toml");
+    fs::write(&config_path, "api_kye = \"typo\"\n").unwrap();
+
+    let err = TripoConfig::from_path(&config_path).unwrap_err();
+    assert!(matches!(err, TripoError::ConfigError(_)));
+}
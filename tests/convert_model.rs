@@ -0,0 +1,168 @@
+use serde_json::json;
+use tripo3d::{
+    ConvertModelRequest, ConvertOptions, OutputFormat, Pivot, TextureFormat, TripoClient, Unit,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_convert_model_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "FBX"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let response = client
+        .convert_model("original_task", OutputFormat::Fbx)
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
+
+#[tokio::test]
+async fn test_convert_model_with_texture_size() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "GLTF",
+            "texture_size": 1024
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ConvertModelRequest {
+        texture_size: Some(1024),
+        ..ConvertModelRequest::new("original_task", OutputFormat::Gltf)
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
+
+#[tokio::test]
+async fn test_convert_model_with_texture_format() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "GLTF",
+            "texture_format": "WEBP"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ConvertModelRequest {
+        texture_format: Some(TextureFormat::Webp),
+        ..ConvertModelRequest::new("original_task", OutputFormat::Gltf)
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
+
+#[tokio::test]
+async fn test_convert_model_with_bottom_center_pivot() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "FBX",
+            "pivot_to_center_bottom": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ConvertModelRequest {
+        pivot: Some(Pivot::BottomCenter),
+        ..ConvertModelRequest::new("original_task", OutputFormat::Fbx)
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
+
+#[tokio::test]
+async fn test_convert_model_in_centimeters() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "FBX",
+            "scale_factor": 100.0
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request =
+        ConvertModelRequest::new("original_task", OutputFormat::Fbx).unit(Unit::Centimeters);
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
+
+#[tokio::test]
+async fn test_convert_model_with_texture_baking() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "original_task",
+            "format": "OBJ",
+            "bake": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let options = ConvertOptions::new().bake(true);
+    let request = ConvertModelRequest::with_options("original_task", OutputFormat::Obj, options);
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "convert_task");
+}
@@ -0,0 +1,80 @@
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::{
+    Deadline, PollSchedule, TaskManager, TaskRequest, TextToModelRequest, TripoClient, TripoError,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_wait_for_task_within_deadline() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "running",
+                "progress": 10,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .poll_schedule(PollSchedule {
+            running: Duration::from_millis(20),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let err = client
+        .wait_for_task_within(
+            "mock_task_id_123",
+            Deadline::after(Duration::from_millis(200)),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::DeadlineExceeded));
+}
+
+#[tokio::test]
+async fn test_submit_batch_within_deadline() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "task_id": "mock_task_id" } }))
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).concurrency(2);
+
+    let requests: Vec<TaskRequest> = ["a cat", "a dog", "a bird"]
+        .into_iter()
+        .map(|prompt| TextToModelRequest::new(prompt).into())
+        .collect();
+    let report = manager
+        .submit_batch_within(requests, Deadline::after(Duration::from_millis(200)))
+        .await;
+
+    // The two submissions already sent finish; the queued one is never sent.
+    assert_eq!(report.submitted.len(), 2);
+    assert_eq!(report.failed.len(), 1);
+    assert!(matches!(
+        report.failed[0],
+        (2, TripoError::DeadlineExceeded)
+    ));
+}
@@ -0,0 +1,136 @@
+use serde_json::json;
+use tripo3d::{DedupeIndex, TaskState, TextToModelRequest, TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_generate_reuses_duplicate_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let index_path = dir.path().join("dedupe.json");
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .dedupe_index(DedupeIndex::persistent(&index_path).unwrap())
+        .build()
+        .unwrap();
+
+    let first = client
+        .generate(TextToModelRequest::new("A delicious hamburger"))
+        .await
+        .unwrap();
+    let second = client
+        .generate(TextToModelRequest::new("  a delicious   HAMBURGER "))
+        .await
+        .unwrap();
+    assert_eq!(first.task_id, second.task_id);
+    assert_eq!(second.status, TaskState::Success);
+
+    let reloaded = DedupeIndex::persistent(&index_path).unwrap();
+    let key = DedupeIndex::key_for(&TextToModelRequest::new("a delicious hamburger").into());
+    assert_eq!(reloaded.get(&key).as_deref(), Some("mock_task_id_123"));
+}
+
+#[tokio::test]
+async fn test_generate_forgets_failed_tasks_in_the_index_file() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/failed_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "failed_task",
+                "status": "failure",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "code": 1004,
+            "message": "invalid parameter"
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let index_path = dir.path().join("dedupe.json");
+    let key = DedupeIndex::key_for(&TextToModelRequest::new("a hamburger").into());
+    std::fs::write(&index_path, json!({ &key: "failed_task" }).to_string()).unwrap();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .dedupe_index(DedupeIndex::persistent(&index_path).unwrap())
+        .build()
+        .unwrap();
+
+    assert!(client
+        .generate(TextToModelRequest::new("a hamburger"))
+        .await
+        .is_err());
+
+    let reloaded = DedupeIndex::persistent(&index_path).unwrap();
+    assert_eq!(reloaded.get(&key), None);
+}
+
+#[tokio::test]
+async fn test_generate_reports_task_id_when_index_write_fails() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let index_path = dir.path().join("missing").join("dedupe.json");
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .dedupe_index(DedupeIndex::persistent(&index_path).unwrap())
+        .build()
+        .unwrap();
+
+    let err = client
+        .generate(TextToModelRequest::new("a hamburger"))
+        .await
+        .unwrap_err();
+    match err {
+        TripoError::Unrecorded { task_id, source } => {
+            assert_eq!(task_id, "mock_task_id_123");
+            assert!(matches!(*source, TripoError::IoError(_)));
+        }
+        other => panic!("expected Unrecorded, got {other:?}"),
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::glb_bytes;
+use serde_json::json;
+use std::fs;
+use std::time::Duration;
+use tripo3d::TripoClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_download_all_models_uses_cache() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "pbr_model": { "url": format!("{}/files/model.glb", server.uri()) }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("files/model.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .cache_dir(cache_dir.path())
+        .build()
+        .unwrap();
+    let status = client.get_task("mock_task_id_123").await.unwrap();
+
+    for _ in 0..2 {
+        let dest_dir = tempfile::tempdir().unwrap();
+        let files = client
+            .download_all_models(&status, dest_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(files, vec![dest_dir.path().join("model.glb")]);
+        assert_eq!(fs::read(&files[0]).unwrap(), glb_bytes());
+    }
+
+    let cached = cache_dir
+        .path()
+        .join("mock_task_id_123")
+        .join("pbr_model")
+        .join("model.glb");
+    assert!(cached.is_file());
+}
+
+#[tokio::test]
+async fn test_concurrent_downloads_share_cache_entry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "pbr_model": { "url": format!("{}/files/model.glb", server.uri()) }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("files/model.glb"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(glb_bytes())
+                .set_delay(Duration::from_millis(50)),
+        )
+        .mount(&server)
+        .await;
+
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .cache_dir(cache_dir.path())
+        .build()
+        .unwrap();
+    let status = client.get_task("mock_task_id_123").await.unwrap();
+
+    let first_dir = tempfile::tempdir().unwrap();
+    let second_dir = tempfile::tempdir().unwrap();
+    let (first, second) = tokio::join!(
+        client.download_all_models(&status, first_dir.path()),
+        client.download_all_models(&status, second_dir.path()),
+    );
+    assert_eq!(fs::read(&first.unwrap()[0]).unwrap(), glb_bytes());
+    assert_eq!(fs::read(&second.unwrap()[0]).unwrap(), glb_bytes());
+
+    let entries: Vec<_> = fs::read_dir(cache_dir.path().join("mock_task_id_123"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, ["pbr_model"]);
+}
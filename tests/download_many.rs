@@ -0,0 +1,54 @@
+mod common;
+
+use common::glb_bytes;
+use tripo3d::{ResultFile, TripoClient};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn test_download_many_reports_each_file() {
+    let server = MockServer::start().await;
+    for name in ["/a.glb", "/b.glb"] {
+        Mock::given(method("GET"))
+            .and(path(name))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/missing.glb"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let downloads = ["/a.glb", "/missing.glb", "/b.glb"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let file = ResultFile {
+                url: server.uri() + name,
+            };
+            (file, dest_dir.path().join(i.to_string()))
+        })
+        .collect();
+    let report = client.download_many(downloads, 2).await;
+
+    assert_eq!(report.results.len(), 3);
+    assert_eq!(
+        report.results[0].as_ref().unwrap(),
+        &dest_dir.path().join("0").join("a.glb")
+    );
+    assert!(report.results[1].is_err());
+    assert_eq!(
+        report.results[2].as_ref().unwrap(),
+        &dest_dir.path().join("2").join("b.glb")
+    );
+    assert_eq!(report.failures, 1);
+    assert_eq!(report.bytes, 2 * glb_bytes().len() as u64);
+}
@@ -1,5 +1,10 @@
+mod common;
+
+use common::glb_bytes;
 use std::fs;
-use tripo3d::{ResultFile, TaskResult, TaskState, TaskStatus, TripoClient};
+use tripo3d::{
+    AssetFilter, AssetKind, ResultFile, TaskResult, TaskState, TaskStatus, TripoClient, TripoError,
+};
 use wiremock::{
     matchers::{method, path_regex},
     Mock, MockServer, ResponseTemplate,
@@ -10,25 +15,28 @@ async fn test_download_model_success() {
     let server = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path_regex(r"/model_.*\.glb"))
-        .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy model data"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
         .mount(&server)
         .await;
-    
+
     let client = TripoClient::new_with_url("test_api_key".to_string(), &server.uri()).unwrap();
 
     let dest_dir = tempfile::tempdir().unwrap();
 
     let task_status = TaskStatus {
         task_id: "mock_task".to_string(),
+        task_type: None,
+        prompt: None,
         status: TaskState::Success,
         progress: 100,
         create_time: 0,
         output: None,
+        consumed_credit: None,
         result: TaskResult {
             pbr_model: Some(ResultFile {
                 url: server.uri() + "/model_download.glb",
             }),
-            glb_model: None,
+            ..Default::default()
         },
     };
 
@@ -45,5 +53,207 @@ async fn test_download_model_success() {
     );
 
     let content = fs::read(file_path).unwrap();
-    assert_eq!(content, b"dummy model data");
-} 
\ No newline at end of file
+    assert_eq!(content, glb_bytes());
+}
+
+#[tokio::test]
+async fn test_download_model_rejects_corrupt_glb() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/expired\.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>Access denied</html>"))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+    let model = ResultFile {
+        url: server.uri() + "/expired.glb",
+    };
+
+    let err = client
+        .download_model(&model, dest_dir.path())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::CorruptDownload { .. }));
+    assert!(!dest_dir.path().join("expired.glb").exists());
+}
+
+#[tokio::test]
+async fn test_download_rendered_video_on_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/model_.*\.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/turntable\.mp4"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy video data"))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let result: TaskResult = serde_json::from_value(serde_json::json!({
+        "pbr_model": { "url": server.uri() + "/model_download.glb" },
+        "rendered_video": { "url": server.uri() + "/turntable.mp4" }
+    }))
+    .unwrap();
+    let kinds: Vec<AssetKind> = result.files().into_iter().map(|(kind, _)| kind).collect();
+    assert_eq!(kinds, [AssetKind::PbrModel, AssetKind::RenderedVideo]);
+
+    let task_status = TaskStatus {
+        task_id: "mock_task".to_string(),
+        task_type: None,
+        prompt: None,
+        status: TaskState::Success,
+        progress: 100,
+        create_time: 0,
+        output: None,
+        consumed_credit: None,
+        result,
+    };
+    let downloaded_files = client
+        .download_all_models(&task_status, dest_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(downloaded_files.len(), 1);
+    assert!(!dest_dir.path().join("turntable.mp4").exists());
+
+    let filter = AssetFilter::new().kind(AssetKind::RenderedVideo);
+    let downloaded_files = client
+        .download_assets(&task_status, dest_dir.path(), &filter)
+        .await
+        .unwrap();
+    assert_eq!(downloaded_files, [dest_dir.path().join("turntable.mp4")]);
+    assert_eq!(
+        fs::read(dest_dir.path().join("turntable.mp4")).unwrap(),
+        b"dummy video data"
+    );
+}
+
+#[tokio::test]
+async fn test_download_assets_with_filter() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/model_.*\.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+        .expect(0)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/preview\.webp"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy image data"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let task_status: TaskStatus = serde_json::from_value(serde_json::json!({
+        "task_id": "mock_task",
+        "status": "success",
+        "progress": 100,
+        "create_time": 0,
+        "output": { "generated_image": server.uri() + "/preview.webp" },
+        "result": {
+            "pbr_model": { "url": server.uri() + "/model_download.glb" }
+        }
+    }))
+    .unwrap();
+    let filter = AssetFilter::new().kind(AssetKind::RenderedImage);
+    let downloaded_files = client
+        .download_assets(&task_status, dest_dir.path(), &filter)
+        .await
+        .unwrap();
+
+    assert_eq!(downloaded_files, [dest_dir.path().join("preview.webp")]);
+}
+
+#[tokio::test]
+async fn test_download_assets_texture_maps() {
+    let server = MockServer::start().await;
+    for map in ["albedo", "normal", "metallic_roughness"] {
+        Mock::given(method("GET"))
+            .and(path_regex(format!(r"/{map}\.png")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(map))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let task_status: TaskStatus = serde_json::from_value(serde_json::json!({
+        "task_id": "mock_task",
+        "status": "success",
+        "progress": 100,
+        "create_time": 0,
+        "result": {
+            "pbr_model": { "url": server.uri() + "/model_download.glb" },
+            "albedo_map": { "url": server.uri() + "/albedo.png" },
+            "normal_map": { "url": server.uri() + "/normal.png" },
+            "metallic_roughness_map": { "url": server.uri() + "/metallic_roughness.png" }
+        }
+    }))
+    .unwrap();
+    let filter = AssetKind::ALL
+        .iter()
+        .filter(|kind| kind.is_texture_map())
+        .fold(AssetFilter::new(), |filter, kind| filter.kind(*kind));
+    let downloaded_files = client
+        .download_assets(&task_status, dest_dir.path(), &filter)
+        .await
+        .unwrap();
+
+    assert_eq!(downloaded_files.len(), 3);
+    assert_eq!(
+        fs::read(dest_dir.path().join("normal.png")).unwrap(),
+        b"normal"
+    );
+}
+
+#[tokio::test]
+async fn test_download_model_resolves_relative_dirs_against_download_dir() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/model_.*\.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+        .mount(&server)
+        .await;
+
+    let download_dir = tempfile::tempdir().unwrap();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .download_dir(download_dir.path())
+        .build()
+        .unwrap();
+    let model = ResultFile {
+        url: server.uri() + "/model_relative.glb",
+    };
+
+    let file_path = client.download_model(&model, "chairs").await.unwrap();
+    assert_eq!(
+        file_path,
+        download_dir
+            .path()
+            .join("chairs")
+            .join("model_relative.glb")
+    );
+
+    let absolute_dir = tempfile::tempdir().unwrap();
+    let file_path = client
+        .download_model(&model, absolute_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(file_path, absolute_dir.path().join("model_relative.glb"));
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::glb_with_json;
+use futures_util::StreamExt;
+use std::fs;
+use tripo3d::{ResultFile, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_download_model_with_progress() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("files/model.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_with_json(&" ".repeat(49_980))))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.glb", server.uri()),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let (events, download) = client.download_model_with_progress(&model, dest_dir.path());
+    let file_path = download.await.unwrap();
+    let events: Vec<_> = events.collect().await;
+
+    assert_eq!(file_path, dest_dir.path().join("model.glb"));
+    assert_eq!(fs::read(&file_path).unwrap().len(), 50_000);
+
+    let last = events.last().unwrap();
+    assert_eq!(last.bytes_done, 50_000);
+    assert_eq!(last.total, Some(50_000));
+    assert_eq!(last.path, file_path);
+}
@@ -0,0 +1,130 @@
+use std::fs;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tripo3d::{ResultFile, TripoClient, TripoError};
+
+/// Serves a body that is cut short on the first request, then the remainder as a
+/// `206 Partial Content` once the client asks for it with a `Range` header.
+async fn truncating_server(
+    body: Vec<u8>,
+    cut: usize,
+) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for attempt in 0..2 {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+            requests.push(request);
+            if attempt == 0 {
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&body[..cut]).await.unwrap();
+            } else {
+                let head = format!(
+                    "HTTP/1.1 206 Partial Content\r\ncontent-length: {}\r\ncontent-range: bytes {}-{}/{}\r\nconnection: close\r\n\r\n",
+                    body.len() - cut,
+                    cut,
+                    body.len() - 1,
+                    body.len()
+                );
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&body[cut..]).await.unwrap();
+            }
+            socket.shutdown().await.unwrap();
+        }
+        requests
+    });
+    (format!("http://{}", addr), handle)
+}
+
+#[tokio::test]
+async fn test_download_model_resumes_truncated_body() {
+    let body: Vec<u8> = (0u8..20).collect();
+    let (base, server) = truncating_server(body.clone(), 10).await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &base).unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", base),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let file_path = client
+        .download_model(&model, dest_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(fs::read(&file_path).unwrap(), body);
+
+    let requests = server.await.unwrap();
+    assert!(!requests[0].contains("range:"));
+    assert!(requests[1].contains("range: bytes=10-"));
+}
+
+#[tokio::test]
+async fn test_download_model_removes_partial_file_when_resume_fails() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        for attempt in 0..2 {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            assert!(socket.read(&mut buf).await.unwrap() > 0);
+            if attempt == 0 {
+                let head = "HTTP/1.1 200 OK\r\ncontent-length: 20\r\nconnection: close\r\n\r\n";
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&[0u8; 10]).await.unwrap();
+            } else {
+                // The presigned URL expired between the attempts.
+                let head =
+                    "HTTP/1.1 403 Forbidden\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+                socket.write_all(head.as_bytes()).await.unwrap();
+            }
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &base).unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", base),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    assert!(client
+        .download_model(&model, dest_dir.path())
+        .await
+        .is_err());
+    assert!(!dest_dir.path().join("model.fbx").exists());
+}
+
+#[tokio::test]
+async fn test_download_model_removes_partial_file_when_chunked_body_fails() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 4096];
+        assert!(socket.read(&mut buf).await.unwrap() > 0);
+        // No content-length, so the cut cannot be resumed: one chunk, then hang up
+        // before the terminating chunk.
+        let head = "HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\nconnection: close\r\n\r\n";
+        socket.write_all(head.as_bytes()).await.unwrap();
+        socket.write_all(b"a\r\n0123456789\r\n").await.unwrap();
+        socket.shutdown().await.unwrap();
+    });
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &base).unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", base),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let result = client.download_model(&model, dest_dir.path()).await;
+    assert!(matches!(result, Err(TripoError::RequestError(_))));
+    assert!(!dest_dir.path().join("model.fbx").exists());
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use tripo3d::{ResultFile, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+/// Serves `body`, honouring single `Range: bytes=a-b` requests like a CDN would.
+struct RangeResponder(Vec<u8>);
+
+impl Respond for RangeResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let Some(range) = request.headers.get("range") else {
+            return ResponseTemplate::new(200).set_body_bytes(self.0.clone());
+        };
+        let (start, end) = range
+            .to_str()
+            .unwrap()
+            .trim_start_matches("bytes=")
+            .split_once('-')
+            .unwrap();
+        let start: usize = start.parse().unwrap();
+        let end: usize = match end {
+            "" => self.0.len() - 1,
+            end => end.parse::<usize>().unwrap().min(self.0.len() - 1),
+        };
+        ResponseTemplate::new(206)
+            .insert_header(
+                "content-range",
+                format!("bytes {}-{}/{}", start, end, self.0.len()),
+            )
+            .set_body_bytes(self.0[start..=end].to_vec())
+    }
+}
+
+#[tokio::test]
+async fn test_download_model_in_segments() {
+    let server = MockServer::start().await;
+    let body: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+
+    Mock::given(method("GET"))
+        .and(path("files/model.fbx"))
+        .respond_with(RangeResponder(body.clone()))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .download_segments(4, 1024)
+        .build()
+        .unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", server.uri()),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let file_path = client
+        .download_model(&model, dest_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(fs::read(&file_path).unwrap(), body);
+
+    let mut ranges: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.headers["range"].to_str().unwrap().to_string())
+        .collect();
+    ranges.sort();
+    assert_eq!(
+        ranges,
+        [
+            "bytes=0-0",
+            "bytes=0-24999",
+            "bytes=25000-49999",
+            "bytes=50000-74999",
+            "bytes=75000-99999",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_small_download_uses_single_connection() {
+    let server = MockServer::start().await;
+    let body = vec![3u8; 512];
+
+    Mock::given(method("GET"))
+        .and(path("files/model.fbx"))
+        .respond_with(RangeResponder(body.clone()))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .download_segments(4, 1024)
+        .build()
+        .unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", server.uri()),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let file_path = client
+        .download_model(&model, dest_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(fs::read(&file_path).unwrap(), body);
+    // The size probe, then one plain request.
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_downloads_are_not_signed_with_the_api_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("files/large.fbx"))
+        .respond_with(RangeResponder(vec![1u8; 4096]))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("files/small.fbx"))
+        .respond_with(RangeResponder(vec![2u8; 512]))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .download_segments(4, 1024)
+        .build()
+        .unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+    for name in ["large.fbx", "small.fbx"] {
+        let model = ResultFile {
+            url: format!("{}/files/{}", server.uri(), name),
+        };
+        client
+            .download_model(&model, dest_dir.path())
+            .await
+            .unwrap();
+    }
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 7);
+    assert!(requests
+        .iter()
+        .all(|request| !request.headers.contains_key("authorization")));
+}
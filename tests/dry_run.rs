@@ -0,0 +1,41 @@
+use tripo3d::{TaskState, TextToModelRequest, TripoClient, TripoError};
+use wiremock::MockServer;
+
+#[tokio::test]
+async fn test_dry_run_never_calls_the_api() {
+    let server = MockServer::start().await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .dry_run(true)
+        .build()
+        .unwrap();
+
+    let response = client.text_to_model("a wooden chair").await.unwrap();
+    assert!(response.task_id.starts_with("dry-run-"));
+
+    let status = client.get_task(&response.task_id).await.unwrap();
+    assert_eq!(status.status, TaskState::Success);
+
+    let status = client
+        .generate(TextToModelRequest::new("a stone bench"))
+        .await
+        .unwrap();
+    assert_eq!(status.status, TaskState::Success);
+    assert_ne!(status.task_id, response.task_id);
+
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_dry_run_still_validates() {
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .dry_run(true)
+        .build()
+        .unwrap();
+
+    let result = client.text_to_model("   ").await;
+    assert!(matches!(result, Err(TripoError::InvalidPrompt(_))));
+}
@@ -0,0 +1,35 @@
+use serde_json::json;
+use tripo3d::{TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// Environment variables are process-wide, so all cases run in a single test.
+#[tokio::test]
+async fn test_client_reads_environment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 42.0, "frozen": 0.0 }
+        })))
+        .mount(&server)
+        .await;
+
+    std::env::set_var("TRIPO_BASE_URL", server.uri());
+    std::env::set_var("TRIPO_TIMEOUT", "5");
+    let client = TripoClient::new(Some("test_api_key".to_string())).unwrap();
+    let balance = client.get_balance().await.unwrap();
+    assert_eq!(balance.balance, 42.0);
+
+    for timeout in ["soon", "-1", "NaN"] {
+        std::env::set_var("TRIPO_TIMEOUT", timeout);
+        let err = TripoClient::new(Some("test_api_key".to_string()))
+            .err()
+            .unwrap();
+        assert!(matches!(err, TripoError::ConfigError(_)));
+    }
+
+    std::env::remove_var("TRIPO_TIMEOUT");
+    std::env::remove_var("TRIPO_BASE_URL");
+}
@@ -0,0 +1,38 @@
+use serde_json::json;
+use tripo3d::{Environment, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_environment_base_urls() {
+    assert_eq!(
+        Environment::Production.base_url(),
+        "https://api.tripo3d.ai/v2/openapi/"
+    );
+    assert_eq!(
+        Environment::Custom("http://localhost:8080/".to_string()).base_url(),
+        "http://localhost:8080/"
+    );
+}
+
+#[tokio::test]
+async fn test_custom_environment_routes_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 10.0, "frozen": 0.0 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .environment(Environment::Custom(format!("{}/", server.uri())))
+        .build()
+        .unwrap();
+
+    assert_eq!(client.get_balance().await.unwrap().balance, 10.0);
+}
@@ -0,0 +1,79 @@
+use tripo3d::{
+    FileContent, ImageTaskRequest, PricingTable, TaskRequest, TextToModelRequest, TextureQuality,
+    TripoClient,
+};
+
+fn client() -> TripoClient {
+    TripoClient::new(Some("test_api_key".to_string())).unwrap()
+}
+
+#[test]
+fn test_estimate_text_to_model_cost() {
+    let client = client();
+
+    let untextured = TaskRequest::from(TextToModelRequest {
+        texture: Some(false),
+        ..TextToModelRequest::new("a cat")
+    });
+    assert_eq!(client.estimate_cost(&untextured).credits, 10.0);
+
+    let standard = TaskRequest::from(TextToModelRequest::new("a cat"));
+    assert_eq!(client.estimate_cost(&standard).credits, 20.0);
+
+    let detailed = TaskRequest::from(TextToModelRequest {
+        texture_quality: Some(TextureQuality::Detailed),
+        ..TextToModelRequest::new("a cat")
+    });
+    assert_eq!(client.estimate_cost(&detailed).credits, 30.0);
+}
+
+#[test]
+fn test_estimate_image_to_model_cost() {
+    let client = client();
+
+    let request = TaskRequest::from(ImageTaskRequest {
+        file: FileContent {
+            type_: "png".to_string(),
+            url: Some("https://example.com/cat.png".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(client.estimate_cost(&request).credits, 30.0);
+
+    let legacy = TaskRequest::from(ImageTaskRequest {
+        model_version: Some("v1.4-20240625".to_string()),
+        texture_quality: Some(TextureQuality::Detailed),
+        ..Default::default()
+    });
+    assert_eq!(client.estimate_cost(&legacy).credits, 30.0);
+}
+
+#[tokio::test]
+async fn test_refresh_pricing() {
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("pricing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "text_to_model": { "base": 5.0, "texture": 5.0, "detailed_texture": 5.0, "legacy": 10.0 },
+                "image_to_model": { "base": 10.0, "texture": 5.0, "detailed_texture": 5.0, "legacy": 15.0 }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    assert_eq!(client.pricing(), PricingTable::default());
+
+    let table = client.refresh_pricing().await.unwrap();
+    assert_eq!(table.text_to_model.base, 5.0);
+
+    let request = TaskRequest::from(TextToModelRequest::new("a cat"));
+    assert_eq!(client.estimate_cost(&request).credits, 10.0);
+}
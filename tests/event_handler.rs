@@ -0,0 +1,129 @@
+mod common;
+
+use common::glb_bytes;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tripo3d::{
+    EventHandler, SubmitOptions, TaskManager, TaskRecord, TaskStatus, TextToModelRequest,
+    TripoClient,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Clone, Default)]
+struct Recorder {
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl EventHandler for Recorder {
+    fn on_submitted(&self, record: &TaskRecord) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("submitted {}", record.task_id));
+    }
+
+    fn on_progress(&self, _record: &TaskRecord, status: &TaskStatus) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("progress {}", status.progress));
+    }
+
+    fn on_success(&self, record: &TaskRecord, _status: &TaskStatus) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("success {}", record.metadata["order"]));
+    }
+
+    fn on_failure(&self, _record: &TaskRecord, _status: &TaskStatus) {
+        self.calls.lock().unwrap().push("failure".to_string());
+    }
+
+    fn on_downloaded(&self, _record: &TaskRecord, paths: &[PathBuf]) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("downloaded {}", paths.len()));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_event_handler_receives_lifecycle() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "running",
+                "progress": 50,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "pbr_model": { "url": format!("{}/model.glb", server.uri()) }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("model.glb"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_bytes()))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let recorder = Recorder::default();
+    let manager = TaskManager::new(client).event_handler(recorder.clone());
+
+    let handle = manager
+        .submit(
+            &TextToModelRequest::new("a wooden chair").into(),
+            SubmitOptions::new().metadata("order", "42"),
+        )
+        .await
+        .unwrap();
+    handle.wait().await.unwrap();
+
+    let dest_dir = tempfile::tempdir().unwrap();
+    handle.download(dest_dir.path()).await.unwrap();
+
+    assert_eq!(
+        *recorder.calls.lock().unwrap(),
+        vec![
+            "submitted mock_task_id_123",
+            "progress 50",
+            "success 42",
+            "downloaded 1"
+        ]
+    );
+}
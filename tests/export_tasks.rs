@@ -0,0 +1,102 @@
+use serde_json::json;
+use std::collections::BTreeMap;
+use tripo3d::{
+    ExportFormat, TaskFilter, TaskRecord, TaskReportWriter, TaskState, TaskStore, TripoClient,
+};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_page(server: &MockServer, page: &str, tasks: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path("task"))
+        .and(query_param("page_num", page))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "tasks": tasks, "total": 101 }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_export_tasks_pages_through_history() {
+    let server = MockServer::start().await;
+    let first_page: Vec<_> = (0..100)
+        .map(|i| {
+            json!({
+                "task_id": format!("task_{}", i),
+                "type": "text_to_model",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1752091365,
+                "consumed_credit": 20.0,
+                "result": { "pbr_model": { "url": "https://example.com/a.glb" } }
+            })
+        })
+        .collect();
+    mount_page(&server, "1", json!(first_page)).await;
+    mount_page(
+        &server,
+        "2",
+        json!([{
+            "task_id": "task_100",
+            "type": "refine_model",
+            "status": "failure",
+            "progress": 0,
+            "create_time": 1752091365,
+            "result": {}
+        }]),
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let mut output = Vec::new();
+    let rows = client
+        .export_tasks(&TaskFilter::new(), ExportFormat::JsonLines, &mut output)
+        .await
+        .unwrap();
+
+    assert_eq!(rows, 101);
+    let lines: Vec<serde_json::Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 101);
+    assert_eq!(lines[0]["task_id"], "task_0");
+    assert_eq!(lines[0]["cost"], 20.0);
+    assert_eq!(lines[0]["created_at"], "2025-07-09T20:02:45Z");
+    assert_eq!(
+        lines[0]["output_urls"],
+        json!(["https://example.com/a.glb"])
+    );
+    assert_eq!(lines[100]["status"], "failure");
+    assert!(lines[100]["cost"].is_null());
+}
+
+#[test]
+fn test_export_store_to_csv() {
+    let store = TaskStore::new();
+    store.insert(TaskRecord {
+        task_id: "task_1".to_string(),
+        task_type: "text_to_model".to_string(),
+        name: None,
+        metadata: BTreeMap::new(),
+        submitted_at: "2025-07-09T20:02:45Z".parse().unwrap(),
+        state: Some(TaskState::Running),
+    });
+
+    let mut output = Vec::new();
+    let mut report = TaskReportWriter::new(&mut output, ExportFormat::Csv);
+    for record in store.records() {
+        report.write(&(&record).into()).unwrap();
+    }
+    assert_eq!(report.finish().unwrap(), 1);
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "task_id,type,status,cost,created_at,output_urls\n\
+         task_1,text_to_model,running,,2025-07-09T20:02:45+00:00,\n"
+    );
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::glb_with_json;
+use std::fs;
+use tripo3d::{FileNaming, ResultFile, TripoClient};
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_url_hash_naming_ignores_query() {
+    let naming = FileNaming::UrlHash;
+    let signed = Url::parse("https://cdn.example.com/a/model.glb?sig=1").unwrap();
+    let resigned = Url::parse("https://cdn.example.com/a/model.glb?sig=2").unwrap();
+    let other = Url::parse("https://cdn.example.com/b/model.glb").unwrap();
+
+    let name = naming.file_name(&signed);
+    assert!(name.starts_with("model-") && name.ends_with(".glb"));
+    assert_eq!(name.len(), "model-12345678.glb".len());
+    assert_eq!(name, naming.file_name(&resigned));
+    assert_ne!(name, naming.file_name(&other));
+    assert_eq!(FileNaming::UrlSegment.file_name(&signed), "model.glb");
+}
+
+#[tokio::test]
+async fn test_downloads_with_same_segment_do_not_collide() {
+    let server = MockServer::start().await;
+
+    for task in ["a", "b"] {
+        Mock::given(method("GET"))
+            .and(path(format!("{}/model.glb", task)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(glb_with_json(task)))
+            .mount(&server)
+            .await;
+    }
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .file_naming(FileNaming::UrlHash)
+        .build()
+        .unwrap();
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let mut paths = Vec::new();
+    for task in ["a", "b"] {
+        let model = ResultFile {
+            url: format!("{}/{}/model.glb", server.uri(), task),
+        };
+        paths.push(
+            client
+                .download_model(&model, dest_dir.path())
+                .await
+                .unwrap(),
+        );
+    }
+
+    assert_ne!(paths[0], paths[1]);
+    assert_eq!(fs::read(&paths[0]).unwrap(), glb_with_json("a"));
+    assert_eq!(fs::read(&paths[1]).unwrap(), glb_with_json("b"));
+}
@@ -0,0 +1,156 @@
+use serde_json::json;
+use tripo3d::{
+    AssetKind, ConvertOptions, GenerateOptions, OutputFormat, TaskState, TextToModelRequest,
+    TripoClient, TripoError,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_task(server: &MockServer, task_id: &str, status: &str, result: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("task/{task_id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": task_id,
+                "status": status,
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": result
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_generate_as_converts_the_generated_model() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a hamburger"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "generate_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_task(&server, "generate_task", "success", json!({})).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "generate_task",
+            "format": "FBX",
+            "texture_size": 1024
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_task(
+        &server,
+        "convert_task",
+        "success",
+        json!({ "model": { "url": "https://example.com/model.fbx" } }),
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let file = client
+        .generate_as(
+            OutputFormat::Fbx,
+            TextToModelRequest::new("a hamburger"),
+            ConvertOptions::new().texture_size(1024),
+        )
+        .await
+        .unwrap();
+    assert_eq!(file.url, "https://example.com/model.fbx");
+}
+
+#[tokio::test]
+async fn test_generate_as_stops_when_generation_fails() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "generate_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_task(&server, "generate_task", "failure", json!({})).await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let err = client
+        .generate_as(
+            OutputFormat::Fbx,
+            TextToModelRequest::new("a hamburger"),
+            ConvertOptions::new(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::TaskFailed { task_id } if task_id == "generate_task"));
+}
+
+#[tokio::test]
+async fn test_generate_with_target_format_returns_the_conversion() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a hamburger"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "generate_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_task(&server, "generate_task", "success", json!({})).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "generate_task",
+            "format": "USDZ"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_task(
+        &server,
+        "convert_task",
+        "success",
+        json!({ "model": { "url": "https://example.com/model.usdz" } }),
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let status = client
+        .generate_with(
+            TextToModelRequest::new("a hamburger"),
+            GenerateOptions::new().target_format(OutputFormat::Usdz),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status.task_id, "convert_task");
+    assert_eq!(status.status, TaskState::Success);
+    assert!(status.result.file(AssetKind::ConvertedFile).is_some());
+}
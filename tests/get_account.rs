@@ -0,0 +1,55 @@
+use serde_json::json;
+use tripo3d::TripoClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_get_account_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "user_id": "user_42",
+                "name": "Ada",
+                "email": "ada@example.com",
+                "plan": "pro",
+                "organization": { "id": "org_7", "name": "Studio" },
+                "limits": { "concurrent_tasks": 10, "requests_per_minute": 600 }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let account = client.get_account().await.unwrap();
+
+    assert_eq!(account.user_id, "user_42");
+    assert_eq!(account.plan.as_deref(), Some("pro"));
+    assert_eq!(account.organization.unwrap().id, "org_7");
+    assert_eq!(account.limits.concurrent_tasks, Some(10));
+    assert_eq!(account.limits.requests_per_minute, Some(600));
+}
+
+#[tokio::test]
+async fn test_get_account_minimal() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "user_id": "user_42" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let account = client.get_account().await.unwrap();
+
+    assert_eq!(account.user_id, "user_42");
+    assert!(account.organization.is_none());
+    assert!(account.limits.concurrent_tasks.is_none());
+}
@@ -1,4 +1,4 @@
-use tripo3d::{TaskState, TaskStatus, TripoClient};
+use tripo3d::{AssetKind, TaskState, TaskStatus, TripoClient};
 use wiremock::{
     matchers::{method, path},
     Mock, MockServer, ResponseTemplate,
@@ -11,7 +11,7 @@ async fn test_get_task_success() {
     let task_id = "mock_task_id_123";
 
     Mock::given(method("GET"))
-        .and(path(&format!("task/{}", task_id)))
+        .and(path(format!("task/{}", task_id)))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "data": {
                 "task_id": "mock_task_id_123",
@@ -40,4 +40,92 @@ async fn test_get_task_success() {
     assert!(response.result.pbr_model.is_some());
     let pbr_model = response.result.pbr_model.unwrap();
     assert_eq!(pbr_model.url, "https://example.com/model1.glb");
-} 
\ No newline at end of file
+} 
+
+#[test]
+fn test_task_output_orientation() {
+    let status: TaskStatus = serde_json::from_value(json!({
+        "task_id": "mock_task_id_123",
+        "status": "success",
+        "progress": 100,
+        "create_time": 1752091365,
+        "output": {
+            "orientation": { "up_axis": "+y", "front_axis": "+z" }
+        },
+        "result": {}
+    }))
+    .unwrap();
+
+    let orientation = status.output.unwrap().orientation.unwrap();
+    assert_eq!(orientation.up_axis, "+y");
+    assert_eq!(orientation.front_axis, "+z");
+}
+
+#[test]
+fn test_rendered_image_from_either_field() {
+    let from_result: TaskStatus = serde_json::from_value(json!({
+        "task_id": "mock_task_id_123",
+        "status": "success",
+        "progress": 100,
+        "create_time": 1752091365,
+        "result": {
+            "rendered_image": { "url": "https://example.com/rendered.webp" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        from_result.rendered_image().unwrap().url,
+        "https://example.com/rendered.webp"
+    );
+
+    let from_output: TaskStatus = serde_json::from_value(json!({
+        "task_id": "mock_task_id_123",
+        "status": "success",
+        "progress": 100,
+        "create_time": 1752091365,
+        "output": { "generated_image": "https://example.com/image.webp" },
+        "result": {}
+    }))
+    .unwrap();
+    assert_eq!(
+        from_output.rendered_image().unwrap().url,
+        "https://example.com/image.webp"
+    );
+}
+
+#[test]
+fn test_assets_classify_model_by_task_type() {
+    let status: TaskStatus = serde_json::from_value(json!({
+        "task_id": "mock_task_id_123",
+        "type": "convert_model",
+        "status": "success",
+        "progress": 100,
+        "create_time": 1752091365,
+        "output": { "generated_image": "https://example.com/image.webp" },
+        "result": {
+            "model": { "url": "https://example.com/model.fbx" }
+        }
+    }))
+    .unwrap();
+
+    let assets: Vec<(AssetKind, String)> = status
+        .assets()
+        .into_iter()
+        .map(|(kind, file)| (kind, file.url))
+        .collect();
+    assert_eq!(
+        assets,
+        [
+            (
+                AssetKind::ConvertedFile,
+                "https://example.com/model.fbx".to_string()
+            ),
+            (
+                AssetKind::RenderedImage,
+                "https://example.com/image.webp".to_string()
+            ),
+        ]
+    );
+    assert!(status.result.file(AssetKind::ConvertedFile).is_some());
+    assert!(status.result.file(AssetKind::PbrModel).is_none());
+}
@@ -0,0 +1,84 @@
+use serde_json::json;
+use tripo3d::{TaskState, TripoClient};
+use wiremock::matchers::{header, method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_get_task_revalidates_with_etag() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(json!({
+                    "data": {
+                        "task_id": "mock_task_id_123",
+                        "status": "running",
+                        "progress": 40,
+                        "create_time": 1678886400,
+                        "result": {}
+                    }
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let first = client.get_task("mock_task_id_123").await.unwrap();
+    let second = client.get_task("mock_task_id_123").await.unwrap();
+
+    assert_eq!(first.status, TaskState::Running);
+    assert_eq!(second.status, TaskState::Running);
+    assert_eq!(second.progress, 40);
+}
+
+#[tokio::test]
+async fn test_get_task_forgets_least_recently_polled_tasks() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/task/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(json!({
+                    "data": {
+                        "task_id": "task",
+                        "status": "running",
+                        "progress": 40,
+                        "create_time": 1678886400,
+                        "result": {}
+                    }
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    // One task more than the cache holds pushes out the first one.
+    for i in 0..=1024 {
+        client.get_task(&format!("task_{}", i)).await.unwrap();
+    }
+    client.get_task("task_0").await.unwrap();
+    client.get_task("task_1024").await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let conditional = |request: &wiremock::Request| request.headers.contains_key("if-none-match");
+    assert!(!conditional(&requests[requests.len() - 2]));
+    assert!(conditional(&requests[requests.len() - 1]));
+}
@@ -0,0 +1,53 @@
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use tripo3d::{Pagination, TimeRange, TripoClient};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_get_usage_history_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/usage"))
+        .and(query_param("page_num", "2"))
+        .and(query_param("page_size", "10"))
+        .and(query_param("start_time", "1752000000"))
+        .and(query_param("end_time", "1752100000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "records": [
+                    {
+                        "task_id": "task_1",
+                        "type": "text_to_model",
+                        "credits": 20.0,
+                        "create_time": 1752091365
+                    }
+                ],
+                "total": 11
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let range = TimeRange {
+        start: Utc.timestamp_opt(1752000000, 0).unwrap(),
+        end: Utc.timestamp_opt(1752100000, 0).unwrap(),
+    };
+    let pagination = Pagination {
+        page: 2,
+        page_size: 10,
+    };
+    let history = client
+        .get_usage_history(Some(range), pagination)
+        .await
+        .unwrap();
+
+    assert_eq!(history.total, 11);
+    assert_eq!(history.records.len(), 1);
+    assert_eq!(history.records[0].task_id, "task_1");
+    assert_eq!(history.records[0].task_type, "text_to_model");
+    assert_eq!(history.records[0].credits, 20.0);
+}
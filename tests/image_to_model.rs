@@ -1,4 +1,4 @@
-use tripo3d::TripoClient;
+use tripo3d::{ImageTaskRequest, Orientation, TexturePrompt, TripoClient};
 use wiremock::matchers::{method, path, body_json};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 use serde_json::json;
@@ -93,4 +93,231 @@ async fn test_image_to_model_with_file_token() {
     let client = TripoClient::new_with_url("test_api_key".to_string(), &server.uri()).unwrap();
     let response = client.image_to_model(file_token).await.unwrap();
     assert_eq!(response.task_id, "task_from_token");
-} 
\ No newline at end of file
+} 
+
+// --- Test Case 4: Detecting the format of a URL input ---
+#[tokio::test]
+async fn test_image_to_model_detects_url_format() {
+    let server = MockServer::start().await;
+    let png_url = "http://example.com/image.PNG";
+    let webp_url = format!("{}/images/42", server.uri());
+
+    Mock::given(method("HEAD"))
+        .and(path("images/42"))
+        .respond_with(ResponseTemplate::new(200).insert_header("Content-Type", "image/webp"))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let from_extension = client.prepare_file_content(png_url, None).await.unwrap();
+    assert_eq!(from_extension.type_, "png");
+
+    let from_head = client.prepare_file_content(&webp_url, None).await.unwrap();
+    assert_eq!(from_head.type_, "webp");
+    let probes = server.received_requests().await.unwrap();
+    assert!(probes
+        .iter()
+        .all(|request| !request.headers.contains_key("authorization")));
+
+    let overridden = client
+        .prepare_file_content(png_url, Some("jpeg"))
+        .await
+        .unwrap();
+    assert_eq!(overridden.type_, "jpeg");
+}
+
+// --- Test Case 5: Sniffing the format of a renamed local file ---
+#[tokio::test]
+async fn test_image_to_model_sniffs_local_file_format() {
+    let server = MockServer::start().await;
+    let file_token = "mock-file-token-from-upload";
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts"))
+        .and(|request: &wiremock::Request| {
+            let needle = b"Content-Type: image/png";
+            request.body.windows(needle.len()).any(|window| window == needle)
+        })
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "image_token": file_token }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "file_token": file_token }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_from_sniffed_file" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let file_path = dir.path().join("photo.jpg");
+    File::create(&file_path)
+        .unwrap()
+        .write_all(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR")
+        .unwrap();
+
+    let response = client.image_to_model(file_path.to_str().unwrap()).await.unwrap();
+    assert_eq!(response.task_id, "task_from_sniffed_file");
+}
+
+// --- Test Case 6: Using a base64 data URI ---
+#[tokio::test]
+async fn test_image_to_model_with_data_uri() {
+    let server = MockServer::start().await;
+    let file_token = "mock-file-token-from-data-uri";
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "image_token": file_token }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "file_token": file_token }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_from_data_uri" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    // "iVBORw0KGgo=" is the base64 encoding of the PNG signature.
+    let response = client
+        .image_to_model("data:image/png;base64,iVBORw0KGgo=")
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "task_from_data_uri");
+}
+
+// --- Test Case 7: Combining image geometry with a texture prompt ---
+#[tokio::test]
+async fn test_image_to_model_with_texture_prompt() {
+    let server = MockServer::start().await;
+    let image_url = "http://example.com/chair.png";
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "url": image_url },
+            "texture_prompt": { "text": "weathered bronze" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_with_texture_prompt" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ImageTaskRequest {
+        file: client.prepare_file_content(image_url, None).await.unwrap(),
+        texture_prompt: Some(TexturePrompt::text("weathered bronze")),
+        ..Default::default()
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "task_with_texture_prompt");
+}
+
+// --- Test Case 8: Transferring the look of a style reference image ---
+#[tokio::test]
+async fn test_image_to_model_with_style_image() {
+    let server = MockServer::start().await;
+    let image_url = "http://example.com/chair.png";
+    let style_token = "123e4567-e89b-12d3-a456-426614174000";
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "url": image_url },
+            "texture_prompt": {
+                "style_image": { "type": "jpeg", "file_token": style_token }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_with_style_image" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let response = client
+        .image_to_model_with_style(image_url, style_token)
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "task_with_style_image");
+}
+
+// --- Test Case 9: Scaling the model to real-world size ---
+#[tokio::test]
+async fn test_image_to_model_with_auto_size() {
+    let server = MockServer::start().await;
+    let image_url = "http://example.com/chair.png";
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "url": image_url },
+            "auto_size": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_with_auto_size" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ImageTaskRequest {
+        file: client.prepare_file_content(image_url, None).await.unwrap(),
+        auto_size: Some(true),
+        ..Default::default()
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "task_with_auto_size");
+}
+
+// --- Test Case 10: Aligning the model to the image's orientation ---
+#[tokio::test]
+async fn test_image_to_model_with_orientation() {
+    let server = MockServer::start().await;
+    let image_url = "http://example.com/chair.png";
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": { "type": "png", "url": image_url },
+            "orientation": "align_image"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_with_orientation" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = ImageTaskRequest {
+        file: client.prepare_file_content(image_url, None).await.unwrap(),
+        orientation: Some(Orientation::AlignImage),
+        ..Default::default()
+    };
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "task_with_orientation");
+}
@@ -0,0 +1,135 @@
+use serde_json::json;
+use std::io::Write;
+use tripo3d::{JobStage, Journal, TaskManager, TaskRequest, TextToModelRequest, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_finished_task(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "pbr_model": { "url": format!("{}/model.fbx", server.uri()) }
+                }
+            }
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("model.fbx"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes("dummy model data"))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_run_job_records_every_stage() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "task_id": "mock_task_id_123" } })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_finished_task(&server).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let journal_path = dir.path().join("jobs.jsonl");
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).journal(Journal::open(&journal_path).unwrap());
+
+    let request = TextToModelRequest::new("a cat").into();
+    let paths = manager
+        .run_job("cat", request, dir.path().join("models"))
+        .await
+        .unwrap();
+    assert_eq!(paths.len(), 1);
+
+    let stages: Vec<String> = std::fs::read_to_string(&journal_path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+            entry["stage"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(
+        stages,
+        ["queued", "submitted", "waiting", "downloading", "done"]
+    );
+
+    let job = Journal::open(&journal_path).unwrap().get("cat").unwrap();
+    assert_eq!(job.stage, JobStage::Done);
+    assert_eq!(job.task_id.as_deref(), Some("mock_task_id_123"));
+}
+
+#[tokio::test]
+async fn test_resume_continues_from_recorded_stage() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+    mount_finished_task(&server).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let journal_path = dir.path().join("jobs.jsonl");
+    let models_dir = dir.path().join("models");
+    let request: TaskRequest = TextToModelRequest::new("a cat").into();
+
+    // A crash left the job waiting, in the middle of writing its next entry.
+    let mut file = std::fs::File::create(&journal_path).unwrap();
+    for entry in [
+        json!({
+            "job": "cat",
+            "stage": "queued",
+            "at": "2024-01-01T00:00:00Z",
+            "request": request,
+            "dest_dir": models_dir,
+        }),
+        json!({
+            "job": "cat",
+            "stage": "submitted",
+            "at": "2024-01-01T00:00:01Z",
+            "task_id": "mock_task_id_123",
+        }),
+        json!({ "job": "cat", "stage": "waiting", "at": "2024-01-01T00:00:02Z" }),
+    ] {
+        writeln!(file, "{entry}").unwrap();
+    }
+    write!(file, r#"{{"job":"cat","stage":"downl"#).unwrap();
+    drop(file);
+
+    let journal = Journal::open(&journal_path).unwrap();
+    let pending = journal.pending();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].stage, JobStage::Waiting);
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).journal(journal);
+
+    let results = manager.resume_jobs().await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, "cat");
+    let paths = results[0].1.as_ref().unwrap();
+    assert!(paths[0].starts_with(&models_dir));
+
+    let journal = Journal::open(&journal_path).unwrap();
+    assert_eq!(journal.get("cat").unwrap().stage, JobStage::Done);
+    assert!(journal.pending().is_empty());
+}
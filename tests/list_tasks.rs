@@ -0,0 +1,96 @@
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use tripo3d::{Pagination, TaskFilter, TaskState, TripoClient};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task(task_id: &str, status: &str, prompt: &str) -> serde_json::Value {
+    json!({
+        "task_id": task_id,
+        "type": "text_to_model",
+        "prompt": prompt,
+        "status": status,
+        "progress": 100,
+        "create_time": 1752091365,
+        "result": {}
+    })
+}
+
+#[tokio::test]
+async fn test_list_tasks_with_filter() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task"))
+        .and(query_param("page_num", "2"))
+        .and(query_param("page_size", "10"))
+        .and(query_param("status", "failure,success"))
+        .and(query_param("type", "text_to_model"))
+        .and(query_param("start_time", "1752000000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "tasks": [
+                    task("a", "success", "A red Dragon"),
+                    task("b", "failure", "a blue whale"),
+                    task("c", "success", "dragon egg"),
+                ],
+                "total": 13
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let filter = TaskFilter::new()
+        .state(TaskState::Success)
+        .state(TaskState::Failure)
+        .task_type("text_to_model")
+        .created_after(Utc.timestamp_opt(1752000000, 0).unwrap())
+        .prompt_contains("dragon");
+    let pagination = Pagination {
+        page: 2,
+        page_size: 10,
+    };
+
+    let list = client.list_tasks(&filter, pagination).await.unwrap();
+
+    let task_ids: Vec<_> = list
+        .tasks
+        .iter()
+        .map(|task| task.task_id.as_str())
+        .collect();
+    assert_eq!(task_ids, ["a", "c"]);
+    assert_eq!(list.total, 13);
+}
+
+#[tokio::test]
+async fn test_list_tasks_filters_locally_when_server_ignores_params() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "tasks": [
+                    task("a", "success", "a chair"),
+                    task("b", "running", "a table"),
+                ],
+                "total": 2
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let filter = TaskFilter::new().state(TaskState::Running);
+
+    let list = client
+        .list_tasks(&filter, Pagination::default())
+        .await
+        .unwrap();
+
+    assert_eq!(list.tasks.len(), 1);
+    assert_eq!(list.tasks[0].task_id, "b");
+}
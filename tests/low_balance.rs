@@ -0,0 +1,88 @@
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use tripo3d::{SubmitOptions, TaskManager, TextToModelRequest, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_balances(server: &MockServer, balances: &[f64]) {
+    for balance in balances {
+        Mock::given(method("GET"))
+            .and(path("user/balance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "balance": balance, "frozen": 0.0 }
+            })))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_on_low_balance_fires_once_per_drop() {
+    let server = MockServer::start().await;
+    mount_balances(&server, &[150.0, 90.0, 80.0, 120.0, 50.0]).await;
+
+    let alerts = Arc::new(Mutex::new(Vec::new()));
+    let recorded = alerts.clone();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .on_low_balance(100.0, move |balance| {
+            recorded.lock().unwrap().push(balance.balance)
+        })
+        .build()
+        .unwrap();
+
+    for _ in 0..5 {
+        client.get_balance().await.unwrap();
+    }
+
+    assert_eq!(*alerts.lock().unwrap(), vec![90.0, 50.0]);
+}
+
+#[tokio::test]
+async fn test_task_manager_checks_balance_after_tasks() {
+    let server = MockServer::start().await;
+    mount_balances(&server, &[10.0]).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let alerts = Arc::new(Mutex::new(Vec::new()));
+    let recorded = alerts.clone();
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).on_low_balance(25.0, move |balance| {
+        recorded.lock().unwrap().push(balance.balance)
+    });
+
+    let handle = manager
+        .submit(
+            &TextToModelRequest::new("a lamp").into(),
+            SubmitOptions::default(),
+        )
+        .await
+        .unwrap();
+    handle.wait().await.unwrap();
+
+    assert_eq!(*alerts.lock().unwrap(), vec![10.0]);
+}
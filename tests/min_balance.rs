@@ -0,0 +1,71 @@
+use serde_json::json;
+use tripo3d::{TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_balance(server: &MockServer, balance: f64) {
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": balance, "frozen": 0.0 }
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_min_balance_allows_submission() {
+    let server = MockServer::start().await;
+    mount_balance(&server, 150.0).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .min_balance(100.0)
+        .build()
+        .unwrap();
+
+    let response = client.text_to_model("a cat").await.unwrap();
+    assert_eq!(response.task_id, "mock_task_id_123");
+}
+
+#[tokio::test]
+async fn test_min_balance_refuses_submission() {
+    let server = MockServer::start().await;
+    mount_balance(&server, 110.0).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .min_balance(100.0)
+        .build()
+        .unwrap();
+
+    let err = client.text_to_model("a cat").await.unwrap_err();
+    match err {
+        TripoError::InsufficientCredits {
+            available,
+            required,
+        } => {
+            assert_eq!(available, 110.0);
+            assert_eq!(required, 120.0);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "mocks")]
+
+use mockall::predicate::eq;
+use tripo3d::{Balance, MockTripoClient, TaskResponse, TripoApi};
+
+async fn submit_if_funded(api: &impl TripoApi, prompt: &str) -> Option<String> {
+    let balance = api.get_balance().await.ok()?;
+    if balance.balance < 10.0 {
+        return None;
+    }
+    api.text_to_model(prompt).await.ok().map(|r| r.task_id)
+}
+
+#[tokio::test]
+async fn test_mock_client_answers_with_expectations() {
+    let mut mock = MockTripoClient::new();
+    mock.expect_get_balance().times(1).returning(|| {
+        Ok(Balance {
+            balance: 100.0,
+            frozen: 0.0,
+        })
+    });
+    mock.expect_text_to_model()
+        .with(eq("a small cat"))
+        .times(1)
+        .returning(|_| {
+            Ok(TaskResponse {
+                task_id: "mock_task_id_123".to_string(),
+            })
+        });
+
+    let task_id = submit_if_funded(&mock, "a small cat").await;
+
+    assert_eq!(task_id.as_deref(), Some("mock_task_id_123"));
+}
@@ -0,0 +1,75 @@
+use serde_json::json;
+use std::fs::File;
+use std::io::Write;
+use tripo3d::{FileContent, MultiviewRequest, TaskRequest, TripoClient};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_multiview_to_model_with_mixed_views() {
+    let server = MockServer::start().await;
+    let file_token = "mock-file-token-from-upload";
+    let back_token = "123e4567-e89b-12d3-a456-426614174000";
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "image_token": file_token }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "multiview_to_model",
+            "files": [
+                { "type": "png", "file_token": file_token },
+                { "type": "jpeg", "url": "http://example.com/left.jpeg" },
+                { "type": "jpeg", "file_token": back_token },
+                {}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "task_from_views" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let front = dir.path().join("front.png");
+    File::create(&front).unwrap().write_all(b"dummy").unwrap();
+
+    let response = client
+        .multiview_to_model(
+            front.to_str().unwrap(),
+            Some("http://example.com/left.jpeg"),
+            Some(back_token),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "task_from_views");
+}
+
+#[test]
+fn test_multiview_request_round_trips_missing_views() {
+    let front = FileContent {
+        type_: "png".to_string(),
+        url: Some("http://example.com/front.png".to_string()),
+        ..Default::default()
+    };
+    let request: TaskRequest = MultiviewRequest::new(front).into();
+
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["files"][1], json!({}));
+
+    let TaskRequest::MultiviewToModel(parsed) = serde_json::from_value(value).unwrap() else {
+        panic!("expected a multiview request");
+    };
+    assert!(parsed.files[0].is_some());
+    assert!(parsed.files[1..].iter().all(Option::is_none));
+}
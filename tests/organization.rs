@@ -0,0 +1,52 @@
+use serde_json::json;
+use tripo3d::{TaskState, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_organization_endpoints() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("organization/task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "org_task" }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("organization/task/org_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "org_task",
+                "status": "pending",
+                "progress": 0,
+                "create_time": 1752091365,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("organization/user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 5000.0, "frozen": 0.0 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .organization(true)
+        .build()
+        .unwrap();
+
+    let response = client.text_to_model("a red fox").await.unwrap();
+    assert_eq!(response.task_id, "org_task");
+
+    let status = client.get_task(&response.task_id).await.unwrap();
+    assert_eq!(status.status, TaskState::Pending);
+
+    assert_eq!(client.get_balance().await.unwrap().balance, 5000.0);
+}
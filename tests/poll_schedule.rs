@@ -0,0 +1,89 @@
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::{PollSchedule, TaskResult, TaskState, TaskStatus, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn status(state: TaskState, progress: u8) -> TaskStatus {
+    TaskStatus {
+        task_id: "task".to_string(),
+        task_type: None,
+        prompt: None,
+        status: state,
+        progress,
+        create_time: 0,
+        result: TaskResult::default(),
+        output: None,
+        consumed_credit: None,
+    }
+}
+
+#[test]
+fn test_poll_schedule_adapts_to_state_and_errors() {
+    let schedule = PollSchedule::default();
+    assert_eq!(
+        schedule.delay_for(&status(TaskState::Pending, 0)),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        schedule.delay_for(&status(TaskState::Running, 30)),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        schedule.delay_for(&status(TaskState::Running, 90)),
+        Duration::from_secs(1)
+    );
+
+    assert_eq!(schedule.backoff_for(1), Duration::from_secs(1));
+    assert_eq!(schedule.backoff_for(3), Duration::from_secs(4));
+    assert_eq!(schedule.backoff_for(10), Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_wait_for_task_retries_network_errors() {
+    let server = MockServer::start().await;
+    let body = json!({
+        "data": {
+            "task_id": "mock_task_id_123",
+            "status": "success",
+            "progress": 100,
+            "create_time": 1678886400,
+            "result": {}
+        }
+    });
+
+    // The first poll times out on the client, the second succeeds.
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(body.clone())
+                .set_delay(Duration::from_secs(2)),
+        )
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .timeout(Duration::from_millis(200))
+        .poll_schedule(PollSchedule {
+            error_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let final_status = client
+        .wait_for_task("mock_task_id_123", false)
+        .await
+        .unwrap();
+    assert_eq!(final_status.status, TaskState::Success);
+}
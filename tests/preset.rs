@@ -0,0 +1,159 @@
+use serde_json::json;
+use tripo3d::preset::{HIGH_QUALITY_MODEL_VERSION, TURBO_MODEL_VERSION};
+use tripo3d::{
+    GenerateOptions, ImageTaskRequest, OutputFormat, Preset, Quality, TextToModelRequest,
+    TextureQuality, TripoClient,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_text_to_model_with_mobile_game_preset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a treasure chest",
+            "texture": true,
+            "texture_quality": "standard",
+            "face_limit": 5000,
+            "compress": "geometry"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "preset_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let request = TextToModelRequest::new("a treasure chest").with_preset(Preset::MobileGame);
+    let response = client.submit_task(&request.into()).await.unwrap();
+    assert_eq!(response.task_id, "preset_task");
+}
+
+#[tokio::test]
+async fn test_generate_with_preset_converts_to_its_export_settings() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a lamp",
+            "texture": true,
+            "texture_quality": "standard",
+            "face_limit": 20000
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "generate_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "convert_model",
+            "original_model_task_id": "generate_task",
+            "format": "USDZ",
+            "texture_size": 2048
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "convert_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for task_id in ["generate_task", "convert_task"] {
+        Mock::given(method("GET"))
+            .and(path(format!("task/{task_id}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "task_id": task_id,
+                    "status": "success",
+                    "progress": 100,
+                    "create_time": 1678886400,
+                    "result": {}
+                }
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let status = client
+        .generate_with(
+            TextToModelRequest::new("a lamp").with_preset(Preset::ArQuickLook),
+            GenerateOptions::new().with_preset(Preset::ArQuickLook),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status.task_id, "convert_task");
+}
+
+#[test]
+fn test_preset_export_settings() {
+    assert_eq!(Preset::ArQuickLook.output_format(), OutputFormat::Usdz);
+    assert_eq!(Preset::HeroAsset.texture_size(), 4096);
+    assert_eq!(Preset::HeroAsset.face_limit(), None);
+}
+
+#[test]
+fn test_quality_levels() {
+    let draft = TextToModelRequest::new("a chair").with_quality(Quality::Draft);
+    assert_eq!(draft.model_version.as_deref(), Some(TURBO_MODEL_VERSION));
+    assert_eq!(draft.texture_quality, Some(TextureQuality::Standard));
+
+    let high = ImageTaskRequest::default().with_quality(Quality::High);
+    assert_eq!(
+        high.model_version.as_deref(),
+        Some(HIGH_QUALITY_MODEL_VERSION)
+    );
+    assert_eq!(high.texture_quality, Some(TextureQuality::Detailed));
+
+    let standard = TextToModelRequest {
+        model_version: Some("v2.0-20240919".to_string()),
+        ..TextToModelRequest::new("a chair")
+    }
+    .with_quality(Quality::Standard);
+    assert_eq!(standard.model_version, None);
+}
+
+#[test]
+fn test_fast_mode() {
+    let fast = TextToModelRequest::new("a chair").fast(true);
+    assert_eq!(fast.model_version.as_deref(), Some(TURBO_MODEL_VERSION));
+    assert_eq!(fast.fast(false).model_version, None);
+
+    let pinned = ImageTaskRequest {
+        model_version: Some(HIGH_QUALITY_MODEL_VERSION.to_string()),
+        ..Default::default()
+    }
+    .fast(false);
+    assert_eq!(
+        pinned.model_version.as_deref(),
+        Some(HIGH_QUALITY_MODEL_VERSION)
+    );
+}
+
+#[test]
+fn test_hd_mode() {
+    let hd = TextToModelRequest::new("a dragon").hd(true);
+    assert_eq!(
+        hd.model_version.as_deref(),
+        Some(HIGH_QUALITY_MODEL_VERSION)
+    );
+    assert_eq!(hd.texture_quality, Some(TextureQuality::Detailed));
+    assert_eq!(hd.texture, Some(true));
+
+    let off = hd.hd(false);
+    assert_eq!(off.model_version, None);
+    assert_eq!(off.texture_quality, None);
+    assert_eq!(off.texture, None);
+}
@@ -0,0 +1,59 @@
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::{Priority, SubmitOptions, TaskManager, TextToModelRequest, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test(start_paused = true)]
+async fn test_high_priority_submissions_jump_the_queue() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "task_id": "mock_task_id" } }))
+                .set_delay(Duration::from_millis(100)),
+        )
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).concurrency(1);
+
+    let mut submissions = Vec::new();
+    for (prompt, priority) in [
+        ("first", Priority::Normal),
+        ("bulk", Priority::Low),
+        ("interactive", Priority::High),
+    ] {
+        let manager = manager.clone();
+        submissions.push(tokio::spawn(async move {
+            let request = TextToModelRequest::new(prompt).into();
+            manager
+                .submit(&request, SubmitOptions::new().priority(priority))
+                .await
+                .map(|_| ())
+        }));
+        // Let each submission queue up before the next one. The clock is paused, so
+        // this only waits for the submission to block, while the first request is
+        // held by the server.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    for submission in submissions {
+        submission.await.unwrap().unwrap();
+    }
+
+    let prompts: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            body["prompt"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(prompts, ["first", "interactive", "bulk"]);
+}
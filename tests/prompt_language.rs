@@ -0,0 +1,16 @@
+use tripo3d::PromptLanguage;
+
+#[test]
+fn test_detect_prompt_language() {
+    assert_eq!(
+        PromptLanguage::detect("a red car"),
+        Some(PromptLanguage::English)
+    );
+    assert_eq!(PromptLanguage::detect("一辆红色的汽车"), None);
+    assert_eq!(PromptLanguage::detect("赤車"), None);
+    assert_eq!(
+        PromptLanguage::detect("赤い車のモデル"),
+        Some(PromptLanguage::Japanese)
+    );
+    assert_eq!(PromptLanguage::detect("красная машина"), None);
+}
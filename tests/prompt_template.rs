@@ -0,0 +1,46 @@
+use serde_json::json;
+use tripo3d::{PromptTemplate, TripoClient, TripoError};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_render_prompt_template() {
+    let template = PromptTemplate::new("a {{{color}}} {object}")
+        .prefix("stylized")
+        .suffix("pbr")
+        .suffix("");
+    let prompt = template
+        .render(&[("color", "red"), ("object", "car")])
+        .unwrap();
+    assert_eq!(prompt, "stylized, a {red} car, pbr");
+
+    let err = template.render(&[("color", "red")]).unwrap_err();
+    assert!(matches!(err, TripoError::TemplateError(_)));
+}
+
+#[tokio::test]
+async fn test_client_prompt_template_decorates_prompts() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "prompt": "a delicious hamburger, high quality",
+            "type": "text_to_model"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .prompt_template(PromptTemplate::new("{prompt}").suffix("high quality"))
+        .build()
+        .unwrap();
+
+    let response = client.text_to_model("a delicious hamburger").await.unwrap();
+    assert_eq!(response.task_id, "mock_task_id_123");
+}
@@ -0,0 +1,82 @@
+use serde_json::json;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tripo3d::{RateLimit, Sleeper, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Records every requested delay and returns immediately.
+#[derive(Clone, Default)]
+struct RecordingSleeper {
+    delays: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl Sleeper for RecordingSleeper {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.delays.lock().unwrap().push(duration);
+        Box::pin(async {})
+    }
+}
+
+#[tokio::test]
+async fn test_requests_wait_for_rate_limit_reset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "balance": 1000.0, "frozen": 0.0 } }))
+                .insert_header("X-RateLimit-Limit", "10")
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Reset", "30"),
+        )
+        .mount(&server)
+        .await;
+
+    let sleeper = RecordingSleeper::default();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .sleeper(sleeper.clone())
+        .build()
+        .unwrap();
+
+    client.get_balance().await.unwrap();
+    assert!(sleeper.delays.lock().unwrap().is_empty());
+    assert_eq!(
+        client.rate_limit(),
+        Some(RateLimit {
+            limit: Some(10),
+            remaining: 0,
+            reset: Duration::from_secs(30),
+        })
+    );
+
+    // The quota is spent, so the next request waits for the reset.
+    client.get_balance().await.unwrap();
+    let delays = sleeper.delays.lock().unwrap();
+    assert_eq!(delays.len(), 1);
+    assert!(delays[0] > Duration::from_secs(29) && delays[0] <= Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_too_many_requests_honors_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "5"))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    assert!(client.get_balance().await.is_err());
+    let limit = client.rate_limit().unwrap();
+    assert_eq!(limit.remaining, 0);
+    assert_eq!(limit.reset, Duration::from_secs(5));
+}
@@ -0,0 +1,124 @@
+use serde_json::json;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tripo3d::{RefineOptions, TaskState, TripoClient, TripoError};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_draft(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a wooden chair",
+            "model_version": "v1.4-20240625"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "draft_task" }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/draft_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "draft_task",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "output": { "generated_image": "https://example.com/draft.webp" },
+                "result": {}
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_generate_refined_refines_the_approved_draft() {
+    let server = MockServer::start().await;
+    mount_draft(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "refine_model",
+            "draft_model_task_id": "draft_task"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "refine_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/refine_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "refine_task",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {
+                    "pbr_model": { "url": "https://example.com/refined.glb" }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let saw_preview = Arc::new(AtomicBool::new(false));
+    let options = RefineOptions::new()
+        .draft_model_version("v1.4-20240625")
+        .approve({
+            let saw_preview = saw_preview.clone();
+            move |draft| {
+                let saw_preview = saw_preview.clone();
+                async move {
+                    tokio::task::yield_now().await;
+                    saw_preview.store(draft.rendered_image().is_some(), Ordering::SeqCst);
+                    true
+                }
+            }
+        });
+
+    let status = client
+        .generate_refined("a wooden chair", options)
+        .await
+        .unwrap();
+    assert_eq!(status.task_id, "refine_task");
+    assert_eq!(status.status, TaskState::Success);
+    assert!(saw_preview.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_generate_refined_stops_at_a_rejected_draft() {
+    let server = MockServer::start().await;
+    mount_draft(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "refine_model",
+            "draft_model_task_id": "draft_task"
+        })))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let options = RefineOptions::new().approve(|_| async { false });
+
+    let err = client
+        .generate_refined("a wooden chair", options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::DraftRejected { task_id } if task_id == "draft_task"));
+}
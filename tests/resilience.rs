@@ -0,0 +1,99 @@
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::{CircuitBreaker, PollSchedule, RetryBudget, TaskState, TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task_body() -> serde_json::Value {
+    json!({
+        "data": {
+            "task_id": "mock_task_id_123",
+            "status": "success",
+            "progress": 100,
+            "create_time": 1678886400,
+            "result": {}
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_opens_and_recovers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_body()))
+        .mount(&server)
+        .await;
+
+    let breaker = CircuitBreaker::new(2, Duration::from_millis(200));
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .circuit_breaker(breaker.clone())
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        let err = client.get_task("mock_task_id_123").await.unwrap_err();
+        assert!(matches!(err, TripoError::ApiError { .. }));
+    }
+    assert!(breaker.is_open("task/{task_id}"));
+    assert!(!breaker.is_open("task"));
+
+    // Refused without reaching the server.
+    let err = client.get_task("mock_task_id_123").await.unwrap_err();
+    assert!(
+        matches!(err, TripoError::CircuitOpen { ref endpoint, .. } if endpoint == "task/{task_id}")
+    );
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    let status = client.get_task("mock_task_id_123").await.unwrap();
+    assert_eq!(status.status, TaskState::Success);
+    assert!(!breaker.is_open("task/{task_id}"));
+}
+
+#[tokio::test]
+async fn test_retry_budget_stops_retries() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(task_body())
+                .set_delay(Duration::from_secs(2)),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let budget = RetryBudget::new(1, 0.1);
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .timeout(Duration::from_millis(100))
+        .poll_schedule(PollSchedule {
+            error_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .retry_budget(budget.clone())
+        .build()
+        .unwrap();
+
+    // One retry is allowed, then the budget is spent and the error is returned.
+    let err = client
+        .wait_for_task("mock_task_id_123", false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::RequestError(_)));
+    assert_eq!(budget.remaining(), 0);
+}
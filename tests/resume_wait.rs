@@ -0,0 +1,107 @@
+use chrono::Utc;
+use serde_json::json;
+use std::collections::BTreeMap;
+use tripo3d::{TaskEvent, TaskManager, TaskRecord, TaskState, TaskStore, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn record(task_id: &str, state: Option<TaskState>) -> TaskRecord {
+    TaskRecord {
+        task_id: task_id.to_string(),
+        task_type: "text_to_model".to_string(),
+        name: None,
+        metadata: BTreeMap::new(),
+        submitted_at: Utc::now(),
+        state,
+    }
+}
+
+#[tokio::test]
+async fn test_resume_waits_reattaches_unfinished_tasks() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/finished_task_id"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    // The store a previous process saved while one task was still running.
+    let dir = tempfile::tempdir().unwrap();
+    let store_path = dir.path().join("tasks.json");
+    let store = TaskStore::new();
+    store.insert(record("mock_task_id_123", Some(TaskState::Running)));
+    store.insert(record("finished_task_id", Some(TaskState::Success)));
+    store.save(&store_path).unwrap();
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).task_store(TaskStore::load(&store_path).unwrap());
+    let mut events = manager.subscribe();
+
+    let results = manager.resume_waits().await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, "mock_task_id_123");
+    assert_eq!(results[0].1.as_ref().unwrap().status, TaskState::Success);
+
+    match events.try_recv().unwrap() {
+        TaskEvent::Finished { record, status } => {
+            assert_eq!(record.task_id, "mock_task_id_123");
+            assert_eq!(record.state, Some(TaskState::Success));
+            assert_eq!(status.status, TaskState::Success);
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+    assert!(manager.store().unfinished().is_empty());
+}
+
+#[tokio::test]
+async fn test_client_resume_wait_updates_store() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "failure",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let store = TaskStore::new();
+    store.insert(record("mock_task_id_123", Some(TaskState::Running)));
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let status = client
+        .resume_wait(&store, "mock_task_id_123")
+        .await
+        .unwrap();
+    assert_eq!(status.status, TaskState::Failure);
+    assert_eq!(
+        store.get("mock_task_id_123").unwrap().state,
+        Some(TaskState::Failure)
+    );
+    assert!(store.unfinished().is_empty());
+}
@@ -0,0 +1,96 @@
+use serde_json::json;
+use tripo3d::{TripoClient, TripoError};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_retopologize_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "highpoly_to_lowpoly",
+            "original_model_task_id": "original_task",
+            "face_limit": 5000,
+            "quad": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "retopology_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/retopology_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "retopology_task",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1752091365,
+                "result": {
+                    "model": { "url": "https://example.com/remeshed.glb" }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let response = client
+        .retopologize("original_task", Some(5000), true)
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "retopology_task");
+
+    let status = client.get_task(&response.task_id).await.unwrap();
+    assert_eq!(
+        status.result.model.unwrap().url,
+        "https://example.com/remeshed.glb"
+    );
+}
+
+#[tokio::test]
+async fn test_decimate_to_returns_the_reduced_model() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "highpoly_to_lowpoly",
+            "original_model_task_id": "original_task",
+            "face_limit": 1000,
+            "quad": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "retopology_task" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/retopology_task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "retopology_task",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1752091365,
+                "result": {
+                    "model": { "url": "https://example.com/lod.glb" }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let model = client.decimate_to("original_task", 1000).await.unwrap();
+    assert_eq!(model.url, "https://example.com/lod.glb");
+
+    let err = client.decimate_to("original_task", 0).await.unwrap_err();
+    assert!(matches!(err, TripoError::InvalidParameter(_)));
+}
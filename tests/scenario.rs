@@ -0,0 +1,65 @@
+#![cfg(feature = "test-support")]
+
+use std::fs;
+use tripo3d::testing::{Scenario, TripoMockServer};
+use tripo3d::{TaskState, TripoError};
+
+/// A binary glTF header followed by a JSON chunk containing "{}".
+fn glb_bytes() -> Vec<u8> {
+    let mut bytes = b"glTF".to_vec();
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    bytes.extend_from_slice(&24u32.to_le_bytes());
+    bytes.extend_from_slice(&4u32.to_le_bytes());
+    bytes.extend_from_slice(b"JSON");
+    bytes.extend_from_slice(b"{}  ");
+    bytes
+}
+
+#[tokio::test]
+async fn test_scenario_plays_back_polls_and_serves_model() {
+    let server = TripoMockServer::start().await;
+    let polls = server
+        .mount(
+            Scenario::task("mock_task_id_123")
+                .pending(2)
+                .running(50)
+                .success_with_model(glb_bytes()),
+        )
+        .await;
+    let client = server.client().unwrap();
+
+    let status = client
+        .wait_for_task("mock_task_id_123", false)
+        .await
+        .unwrap();
+    assert_eq!(status.status, TaskState::Success);
+    assert_eq!(polls.count(), 4);
+
+    let dest_dir = tempfile::tempdir().unwrap();
+    let files = client
+        .download_all_models(&status, dest_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(fs::read(&files[0]).unwrap(), glb_bytes());
+}
+
+#[tokio::test]
+async fn test_scenario_http_error_surfaces_as_api_error() {
+    let server = TripoMockServer::start().await;
+    server
+        .mount(
+            Scenario::task("mock_task_id_123")
+                .running(10)
+                .http_error(500),
+        )
+        .await;
+    let client = server.client().unwrap();
+
+    let first = client.get_task("mock_task_id_123").await.unwrap();
+    assert_eq!(first.status, TaskState::Running);
+    assert_eq!(first.progress, 10);
+
+    let err = client.get_task("mock_task_id_123").await.unwrap_err();
+    assert!(matches!(err, TripoError::ApiError { .. }));
+}
@@ -0,0 +1,116 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tripo3d::{
+    ShutdownOptions, Sleeper, SubmitOptions, TaskManager, TaskStore, TextToModelRequest,
+    TripoClient, TripoError,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Holds every sleep until the test releases it, announcing that it started.
+#[derive(Clone, Default)]
+struct GateSleeper {
+    entered: Arc<Notify>,
+    release: Arc<Notify>,
+}
+
+impl Sleeper for GateSleeper {
+    fn sleep(&self, _duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.entered.notify_one();
+        Box::pin(self.release.notified())
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_and_persists() {
+    let server = MockServer::start().await;
+
+    // A spent rate limit makes the next submission wait in the sleeper.
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "balance": 1000.0, "frozen": 0.0 } }))
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Reset", "30"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "data": { "task_id": "mock_task_id" } })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sleeper = GateSleeper::default();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .sleeper(sleeper.clone())
+        .build()
+        .unwrap();
+    client.get_balance().await.unwrap();
+    let manager = TaskManager::new(client);
+
+    let in_flight = tokio::spawn({
+        let manager = manager.clone();
+        async move {
+            let request = TextToModelRequest::new("a cat").into();
+            manager
+                .submit(&request, SubmitOptions::new().name("cat"))
+                .await
+        }
+    });
+    sleeper.entered.notified().await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let store_path = dir.path().join("tasks.json");
+    let (report, ()) = tokio::join!(
+        manager.shutdown(ShutdownOptions::new().persist_to(&store_path)),
+        async { sleeper.release.notify_one() },
+    );
+
+    assert!(report.unwrap().drained);
+    assert_eq!(in_flight.await.unwrap().unwrap().task_id(), "mock_task_id");
+
+    let request = TextToModelRequest::new("a dog").into();
+    let err = manager
+        .submit(&request, SubmitOptions::new())
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(err, TripoError::ShutDown));
+
+    let store = TaskStore::load(&store_path).unwrap();
+    assert_eq!(store.find_by_name("cat").unwrap().task_id, "mock_task_id");
+}
+
+#[tokio::test]
+async fn test_close_watchers_ends_streams() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 1000.0, "frozen": 0.0 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let mut balances = Box::pin(client.watch_balance(Duration::from_millis(10)));
+    assert!(balances.next().await.unwrap().is_ok());
+
+    client.clone().close_watchers();
+    assert!(balances.next().await.is_none());
+}
@@ -0,0 +1,84 @@
+use serde_json::json;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tripo3d::{PollSchedule, Sleeper, TaskState, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Records every requested delay and returns immediately.
+#[derive(Clone, Default)]
+struct RecordingSleeper {
+    delays: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl Sleeper for RecordingSleeper {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.delays.lock().unwrap().push(duration);
+        Box::pin(async {})
+    }
+}
+
+fn task_body(status: &str, progress: u8) -> serde_json::Value {
+    json!({
+        "data": {
+            "task_id": "mock_task_id_123",
+            "status": status,
+            "progress": progress,
+            "create_time": 1678886400,
+            "result": {}
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_wait_for_task_uses_injected_sleeper() {
+    let server = MockServer::start().await;
+
+    for (status, progress) in [("pending", 0), ("running", 40), ("running", 90)] {
+        Mock::given(method("GET"))
+            .and(path("task/mock_task_id_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(task_body(status, progress)))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_body("success", 100)))
+        .mount(&server)
+        .await;
+
+    let sleeper = RecordingSleeper::default();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .poll_schedule(PollSchedule {
+            pending: Duration::from_secs(60),
+            running: Duration::from_secs(30),
+            finishing: Duration::from_secs(10),
+            ..Default::default()
+        })
+        .sleeper(sleeper.clone())
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    let status = client
+        .wait_for_task("mock_task_id_123", false)
+        .await
+        .unwrap();
+
+    assert_eq!(status.status, TaskState::Success);
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        *sleeper.delays.lock().unwrap(),
+        vec![
+            Duration::from_secs(60),
+            Duration::from_secs(30),
+            Duration::from_secs(10),
+        ]
+    );
+}
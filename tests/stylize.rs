@@ -0,0 +1,94 @@
+use serde_json::json;
+use tripo3d::{Stylization, StylizeRequest, TaskRequest, TripoClient, TripoError};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_stylize_model_sends_style_parameters() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "stylize_model",
+            "original_model_task_id": "original_task",
+            "style": "lego",
+            "block_size": 64
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "stylize_task" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let response = client
+        .stylize_model(
+            "original_task",
+            Stylization::Lego {
+                block_size: Some(64),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "stylize_task");
+}
+
+#[test]
+fn test_stylize_request_round_trips() {
+    let request: TaskRequest = StylizeRequest::new("original_task", Stylization::Minecraft).into();
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "type": "stylize_model",
+            "original_model_task_id": "original_task",
+            "style": "minecraft"
+        })
+    );
+
+    let parsed: TaskRequest = serde_json::from_value(json!({
+        "type": "stylize_model",
+        "original_model_task_id": "original_task",
+        "style": "voronoi",
+        "cell_size": 0.25
+    }))
+    .unwrap();
+    let TaskRequest::Stylize(parsed) = parsed else {
+        panic!("expected a stylize request");
+    };
+    assert_eq!(
+        parsed.style,
+        Stylization::Voronoi {
+            cell_size: Some(0.25)
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_stylize_model_rejects_out_of_range_sizes() {
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), "http://localhost").unwrap();
+
+    for style in [
+        Stylization::Lego {
+            block_size: Some(8),
+        },
+        Stylization::Voxel {
+            resolution: Some(1024),
+        },
+    ] {
+        let err = client
+            .stylize_model("original_task", style)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TripoError::InvalidParameter(_)), "{err:?}");
+    }
+
+    assert!(Stylization::Voxel {
+        resolution: Some(64)
+    }
+    .validate()
+    .is_ok());
+}
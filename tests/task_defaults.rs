@@ -0,0 +1,67 @@
+use serde_json::json;
+use std::fs;
+use tripo3d::{
+    TaskDefaults, TaskRequest, TextToModelRequest, TextureQuality, TripoClient, TripoConfig,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_client_defaults_fill_unset_options() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "text_to_model",
+            "prompt": "a cat",
+            "model_version": "v2.5-20250123",
+            "texture_quality": "detailed",
+            "face_limit": 5000,
+            "style": "object:clay"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .defaults(TaskDefaults {
+            model_version: Some("v2.5-20250123".to_string()),
+            texture_quality: Some(TextureQuality::Detailed),
+            face_limit: Some(10000),
+            style: Some("object:clay".to_string()),
+        })
+        .build()
+        .unwrap();
+
+    let request = TextToModelRequest {
+        face_limit: Some(5000),
+        ..TextToModelRequest::new("a cat")
+    };
+    let response = client
+        .submit_task(&TaskRequest::TextToModel(request))
+        .await
+        .unwrap();
+    assert_eq!(response.task_id, "mock_task_id_123");
+}
+
+#[test]
+fn test_config_defaults_section() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("tripo.toml");
+    fs::write(
+        &config_path,
+        "api_key = \"test_api_key\"\n\n[defaults]\nmodel_version = \"v2.5-20250123\"\ntexture_quality = \"detailed\"\n",
+    )
+    .unwrap();
+
+    let config = TripoConfig::from_path(&config_path).unwrap();
+    let defaults = config.defaults.unwrap();
+    assert_eq!(defaults.model_version.as_deref(), Some("v2.5-20250123"));
+    assert_eq!(defaults.texture_quality, Some(TextureQuality::Detailed));
+    assert_eq!(defaults.face_limit, None);
+}
@@ -0,0 +1,34 @@
+use serde_json::json;
+use tripo3d::{TaskManager, TaskRequest, TextToModelRequest, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_submit_batch_respects_budget() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id" }
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client).concurrency(2).budget(50.0);
+
+    let requests: Vec<TaskRequest> = ["a cat", "a dog", "a bird"]
+        .into_iter()
+        .map(|prompt| TextToModelRequest::new(prompt).into())
+        .collect();
+    let report = manager.submit_batch(requests).await;
+
+    assert_eq!(report.submitted.len(), 2);
+    assert_eq!(report.submitted[0].0, 0);
+    assert_eq!(report.submitted[1].0, 1);
+    assert!(report.failed.is_empty());
+    assert_eq!(report.skipped, vec![2]);
+    assert_eq!(report.estimated_credits, 40.0);
+}
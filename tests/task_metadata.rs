@@ -0,0 +1,69 @@
+use serde_json::json;
+use tripo3d::{SubmitOptions, TaskEvent, TaskManager, TaskState, TextToModelRequest, TripoClient};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_submit_with_metadata() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": "mock_task_id_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client);
+    let mut events = manager.subscribe();
+
+    let handle = manager
+        .submit(
+            &TextToModelRequest::new("a wooden chair").into(),
+            SubmitOptions::new().metadata("asset_id", "chair-42"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(handle.task_id(), "mock_task_id_123");
+    assert_eq!(handle.metadata()["asset_id"], "chair-42");
+
+    let status = handle.wait().await.unwrap();
+    assert_eq!(status.status, TaskState::Success);
+
+    match events.recv().await.unwrap() {
+        TaskEvent::Submitted(record) => {
+            assert_eq!(record.task_type, "text_to_model");
+            assert_eq!(record.metadata["asset_id"], "chair-42");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+    match events.recv().await.unwrap() {
+        TaskEvent::Finished { record, status } => {
+            assert_eq!(record.metadata["asset_id"], "chair-42");
+            assert_eq!(record.state, Some(TaskState::Success));
+            assert_eq!(status.task_id, "mock_task_id_123");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+
+    let found = manager.store().find_by_metadata("asset_id", "chair-42");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].task_id, "mock_task_id_123");
+}
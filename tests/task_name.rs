@@ -0,0 +1,51 @@
+use serde_json::json;
+use tripo3d::{SubmitOptions, TaskManager, TextToModelRequest, TripoClient};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_find_task_by_name() {
+    let server = MockServer::start().await;
+
+    for (prompt, task_id) in [("chair v2", "task_v2"), ("chair v3", "task_v3")] {
+        Mock::given(method("POST"))
+            .and(path("task"))
+            .and(body_partial_json(json!({ "prompt": prompt })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "task_id": task_id }
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let manager = TaskManager::new(client);
+
+    manager
+        .submit(
+            &TextToModelRequest::new("chair v2").into(),
+            SubmitOptions::new().name("kitchen-chair"),
+        )
+        .await
+        .unwrap();
+    manager
+        .submit(
+            &TextToModelRequest::new("chair v3").into(),
+            SubmitOptions::new().name("kitchen-chair-v3"),
+        )
+        .await
+        .unwrap();
+
+    let handle = manager.find_task_by_name("kitchen-chair-v3").unwrap();
+    assert_eq!(handle.task_id(), "task_v3");
+    assert_eq!(handle.name().as_deref(), Some("kitchen-chair-v3"));
+    assert_eq!(
+        manager
+            .find_task_by_name("kitchen-chair")
+            .unwrap()
+            .task_id(),
+        "task_v2"
+    );
+    assert!(manager.find_task_by_name("unknown").is_none());
+}
@@ -1,4 +1,5 @@
-use tripo3d::TripoClient;
+use tripo3d::prompt::MAX_PROMPT_LENGTH;
+use tripo3d::{TripoClient, TripoError};
 use wiremock::matchers::{method, path, body_json};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 use serde_json::json;
@@ -26,4 +27,25 @@ async fn test_text_to_model_success() {
     let response = client.text_to_model("a delicious hamburger").await.unwrap();
 
     assert_eq!(response.task_id, "mock_task_id_123");
-} 
\ No newline at end of file
+} 
+
+#[tokio::test]
+async fn test_text_to_model_rejects_invalid_prompt() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let err = client.text_to_model("   ").await.unwrap_err();
+    assert!(matches!(err, TripoError::InvalidPrompt(_)));
+
+    let too_long = "a".repeat(MAX_PROMPT_LENGTH + 1);
+    let err = client.text_to_model(&too_long).await.unwrap_err();
+    assert!(matches!(err, TripoError::InvalidPrompt(_)));
+}
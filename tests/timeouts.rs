@@ -0,0 +1,61 @@
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tripo3d::{ResultFile, TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_read_timeout_fails_stalled_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("files/model.fbx"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(vec![1u8; 16])
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .read_timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+    let model = ResultFile {
+        url: format!("{}/files/model.fbx", server.uri()),
+    };
+    let dest_dir = tempfile::tempdir().unwrap();
+
+    let started = Instant::now();
+    let result = client.download_model(&model, dest_dir.path()).await;
+    assert!(matches!(result, Err(TripoError::RequestError(e)) if e.is_timeout()));
+    assert!(started.elapsed() < Duration::from_secs(4));
+}
+
+#[tokio::test]
+async fn test_ws_handshake_timeout() {
+    // Accepts the TCP connection but never answers the upgrade request.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (_stream, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(10)).await;
+    });
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(format!("ws://{}/", addr))
+        .ws_handshake_timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    let result = client.watch_task("task_123").await;
+    assert!(
+        matches!(result, Err(TripoError::IoError(e)) if e.kind() == std::io::ErrorKind::TimedOut)
+    );
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,120 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use tripo3d::{TripoClient, TripoError};
+
+/// A self-signed certificate standing in for a corporate CA.
+const CA_PEM: &str = "\
+-----BEGIN CERTIFICATE-----\n\
+MIIDEzCCAfugAwIBAgIUPlRSHV7dye9YZIjhgZQ+jDSZWMkwDQYJKoZIhvcNAQEL\n\
+BQAwGDEWMBQGA1UEAwwNdHJpcG8tdGVzdC1jYTAgFw0yNjEwMTYyMDEyNDhaGA8y\n\
+MTI2MDkyMjIwMTI0OFowGDEWMBQGA1UEAwwNdHJpcG8tdGVzdC1jYTCCASIwDQYJ\n\
+KoZIhvcNAQEBBQADggEPADCCAQoCggEBAPBckz9EmLj7tQ9z2+/AsbPmhAYF1G/k\n\
+1Kv2dVQDMeiyhvfR8y+edCOsG5jxlmxOC9Vs9SvG6aoNMPSvkRnkdLWGB7DguKqG\n\
+SrMZsgQs5RYCh70eWbsnOqUcTm63+M+25ronR3YTkfTcz1qg1nyP2iRrcLGqX2as\n\
+x7Fcgey0T6T5cueXHQ+qtPReJCJwCbLVablDV9nR42fd6MbQq86SDOffRCyAlTQw\n\
+UnOCGzVrIGHD1Cq4VHyaUlK5duee7jGI+Mxfcqj4sieJGQlfXbJNZmSu6//fUw2L\n\
+gnq7zzp8HkZ6qu3TCH9bjEQY5AmhXNS5hkCdvEE8aqEkJqnP9QIQuKcCAwEAAaNT\n\
+MFEwHQYDVR0OBBYEFB1H7wCtmY6QPIID+H35/AzXqzN5MB8GA1UdIwQYMBaAFB1H\n\
+7wCtmY6QPIID+H35/AzXqzN5MA8GA1UdEwEB/wQFMAMBAf8wDQYJKoZIhvcNAQEL\n\
+BQADggEBAOe/en0iNK3wdYuCuC/F+tyozJdGIND6zlzwHXId/GWZMfwQDFA4zrv2\n\
+4IxWEi28+Vhs7vfawB6N3bHMsjBFYGd4iuTZXhIHUxRhnlyLGRpIx0VLNoeAOzvM\n\
+g69V7tle+TJT6ZWFWyFdQyMyej3YXTiqnSMWcwM91OdyqKSGS4Vjjak8cg9xDFWX\n\
+2M9670sbBQahKT4jDdg4rrvlegdTWB91HdbG9xPQdXd7PZ+2sf3gzrVKy3noNQQS\n\
+7glio2AU9B6cthkj1ZNF44ZcTgJyhBilCvPNDTsl40oyPguUP3MbomnqL6s5RWFA\n\
+92OMgbjB110jx77C2V7PcFe1a+qppqU=\n\
+-----END CERTIFICATE-----\n\
+";
+
+/// The CA that issued the certificate of the server started by [`spawn_tls_server`].
+const SERVER_CA_PEM: &str = include_str!("fixtures/tls/server-ca.pem");
+
+/// Starts an HTTPS server on 127.0.0.1 that answers every request with a balance.
+fn spawn_tls_server() -> SocketAddr {
+    let identity = native_tls::Identity::from_pkcs8(
+        include_bytes!("fixtures/tls/server.pem"),
+        include_bytes!("fixtures/tls/server.key"),
+    )
+    .unwrap();
+    let acceptor = native_tls::TlsAcceptor::new(identity).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            // Handshakes the client refuses end here.
+            let Ok(mut stream) = acceptor.accept(stream.unwrap()) else {
+                continue;
+            };
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+            let body = r#"{"data":{"balance":100.0,"frozen":0.0}}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    addr
+}
+
+#[test]
+fn test_build_with_root_certificate() {
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .root_certificate(CA_PEM)
+        .build();
+    assert!(client.is_ok());
+}
+
+#[test]
+fn test_build_with_exclusive_root_certificate() {
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .exclusive_root_certificate(CA_PEM)
+        .build();
+    assert!(client.is_ok());
+}
+
+#[tokio::test]
+async fn test_exclusive_root_certificate_accepts_servers_it_issued() {
+    let addr = spawn_tls_server();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(format!("https://{}", addr))
+        .exclusive_root_certificate(SERVER_CA_PEM)
+        .build()
+        .unwrap();
+
+    let balance = client.get_balance().await.unwrap();
+    assert_eq!(balance.balance, 100.0);
+}
+
+#[tokio::test]
+async fn test_exclusive_root_certificate_rejects_other_servers() {
+    let addr = spawn_tls_server();
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(format!("https://{}", addr))
+        .exclusive_root_certificate(CA_PEM)
+        .build()
+        .unwrap();
+
+    let err = client.get_balance().await.unwrap_err();
+    assert!(matches!(err, TripoError::RequestError(ref e) if e.is_connect()));
+}
+
+#[test]
+fn test_build_rejects_invalid_certificate() {
+    let result = TripoClient::builder()
+        .api_key("test_api_key")
+        .root_certificate("not a certificate")
+        .build();
+    assert!(matches!(result, Err(TripoError::RequestError(_))));
+}
@@ -0,0 +1,175 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{AccessToken, TripoClient, TripoError};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_balance(server: &MockServer, token: &str, expected: u64) {
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .and(header(
+            "Authorization",
+            format!("Bearer {}", token).as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "balance": 100.0,
+                "frozen": 0.0
+            }
+        })))
+        .expect(expected)
+        .mount(server)
+        .await;
+}
+
+/// A token source that numbers its tokens and gives each the lifetime `lifetime`.
+fn numbered_tokens(
+    lifetime: Duration,
+) -> (
+    Arc<AtomicUsize>,
+    impl Fn() -> futures_util::future::Ready<Result<AccessToken, TripoError>>,
+) {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = fetches.clone();
+    let source = move || {
+        let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        futures_util::future::ready(Ok(
+            AccessToken::new(format!("token_{}", n)).expires_in(lifetime)
+        ))
+    };
+    (fetches, source)
+}
+
+#[tokio::test]
+async fn test_token_source_reuses_a_fresh_token() {
+    let server = MockServer::start().await;
+    mount_balance(&server, "token_1", 2).await;
+
+    let (fetches, source) = numbered_tokens(Duration::from_secs(3600));
+    let client = TripoClient::builder()
+        .token_source(source)
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    client.get_balance().await.unwrap();
+    client.clone().get_balance().await.unwrap();
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_token_source_refreshes_an_expiring_token() {
+    let server = MockServer::start().await;
+    mount_balance(&server, "token_1", 1).await;
+    mount_balance(&server, "token_2", 1).await;
+
+    // Tokens expiring within the refresh margin are replaced before every request.
+    let (fetches, source) = numbered_tokens(Duration::from_secs(5));
+    let client = TripoClient::builder()
+        .token_source(source)
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    client.get_balance().await.unwrap();
+    client.get_balance().await.unwrap();
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_token_source_replaces_a_rejected_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .and(header("Authorization", "Bearer token_1"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_balance(&server, "token_2", 1).await;
+
+    let (fetches, source) = numbered_tokens(Duration::from_secs(3600));
+    let client = TripoClient::builder()
+        .token_source(source)
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    assert!(client.get_balance().await.is_err());
+    client.get_balance().await.unwrap();
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_token_source_fetch_times_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .token_source(futures_util::future::pending::<Result<AccessToken, TripoError>>)
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let err = client.get_balance().await.unwrap_err();
+    assert!(matches!(err, TripoError::IoError(ref e) if e.kind() == std::io::ErrorKind::TimedOut));
+}
+
+#[tokio::test]
+async fn test_token_source_signs_watch_handshakes() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let authorization = Arc::new(Mutex::new(None));
+    let recorded = authorization.clone();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        // The handshake callback's error type is tungstenite's, not ours.
+        #[allow(clippy::result_large_err)]
+        let callback = |request: &Request, response: Response| {
+            *recorded.lock().unwrap() = request
+                .headers()
+                .get("Authorization")
+                .map(|value| value.to_str().unwrap().to_string());
+            Ok(response)
+        };
+        let mut ws = tokio_tungstenite::accept_hdr_async(stream, callback)
+            .await
+            .unwrap();
+        let update = json!({
+            "data": {
+                "task_id": "task_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        });
+        ws.send(Message::Text(update.to_string())).await.unwrap();
+        ws.close(None).await.unwrap();
+    });
+
+    let (_, source) = numbered_tokens(Duration::from_secs(3600));
+    let client = TripoClient::builder()
+        .token_source(source)
+        .ws_url(format!("ws://{}/", addr))
+        .build()
+        .unwrap();
+
+    let updates: Vec<_> = client.watch_task("task_123").await.unwrap().collect().await;
+    assert_eq!(updates.len(), 1);
+    assert_eq!(
+        authorization.lock().unwrap().as_deref(),
+        Some("Bearer token_1")
+    );
+}
@@ -0,0 +1,39 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use std::fs;
+use tripo3d::TripoClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_upload_file_with_progress() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "image_token": "mock_file_token" }
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let image_path = dir.path().join("image.png");
+    fs::write(&image_path, vec![7u8; 20_000]).unwrap();
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let (events, upload) = client.upload_file_with_progress(&image_path);
+
+    let file_token = upload.await.unwrap();
+    let events: Vec<_> = events.collect().await;
+
+    assert_eq!(file_token, "mock_file_token");
+    assert!(!events.is_empty());
+    assert!(events
+        .windows(2)
+        .all(|pair| pair[0].bytes_sent < pair[1].bytes_sent));
+    let last = events.last().unwrap();
+    assert_eq!(last.bytes_sent, 20_000);
+    assert_eq!(last.total, 20_000);
+}
@@ -0,0 +1,195 @@
+use serde_json::json;
+use std::fs;
+use tripo3d::{TripoClient, TripoError};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// The smallest part size S3 accepts.
+const PART_SIZE: usize = 5 * 1024 * 1024;
+
+fn has_query(name: &'static str) -> impl Fn(&Request) -> bool {
+    move |request: &Request| request.url.query_pairs().any(|(key, _)| key == name)
+}
+
+#[tokio::test]
+async fn test_upload_file_s3_multipart() {
+    std::env::set_var("AWS_REGION", "us-east-1");
+    std::env::set_var("AWS_EC2_METADATA_DISABLED", "true");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "sts_ak": "ak",
+                "sts_sk": "sk",
+                "session_token": "token",
+                "resource_bucket": "test-bucket",
+                "resource_uri": "uploads/image.png"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("test-bucket/uploads/image.png"))
+        .and(has_query("uploads"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<InitiateMultipartUploadResult><Bucket>test-bucket</Bucket>\
+             <Key>uploads/image.png</Key><UploadId>upload-1</UploadId>\
+             </InitiateMultipartUploadResult>",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("test-bucket/uploads/image.png"))
+        .and(has_query("partNumber"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"part\""))
+        .expect(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("test-bucket/uploads/image.png"))
+        .and(has_query("uploadId"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<CompleteMultipartUploadResult><Bucket>test-bucket</Bucket>\
+             <Key>uploads/image.png</Key><ETag>\"done\"</ETag>\
+             </CompleteMultipartUploadResult>",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let image_path = dir.path().join("image.png");
+    fs::write(&image_path, vec![7u8; 2 * PART_SIZE + 500]).unwrap();
+
+    let mut client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .s3_part_size(PART_SIZE)
+        .build()
+        .unwrap();
+    client.s3_endpoint_override = Some(server.uri());
+
+    let content = client.upload_file_s3(&image_path).await.unwrap();
+    let object = content.object.unwrap();
+    assert_eq!(object.bucket, "test-bucket");
+    assert_eq!(object.key, "uploads/image.png");
+}
+
+#[test]
+fn test_s3_part_size_below_minimum_is_rejected() {
+    let err = TripoClient::builder()
+        .api_key("test_api_key")
+        .s3_part_size(PART_SIZE - 1)
+        .build()
+        .err()
+        .unwrap();
+    assert!(matches!(err, TripoError::ConfigError(_)));
+}
+
+#[tokio::test]
+async fn test_upload_file_s3_with_builder_settings() {
+    std::env::set_var("AWS_EC2_METADATA_DISABLED", "true");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "sts_ak": "ak",
+                "sts_sk": "sk",
+                "session_token": "token",
+                "resource_bucket": "test-bucket",
+                "resource_uri": "uploads/image.png"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("test-bucket/uploads/image.png"))
+        .and(|request: &Request| {
+            request
+                .headers
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.contains("/eu-west-1/s3/"))
+        })
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let image_path = dir.path().join("image.png");
+    fs::write(&image_path, vec![7u8; 100]).unwrap();
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .s3_endpoint(server.uri())
+        .s3_region("eu-west-1")
+        .s3_path_style(true)
+        .build()
+        .unwrap();
+
+    let content = client.upload_file_s3(&image_path).await.unwrap();
+    assert_eq!(content.object.unwrap().key, "uploads/image.png");
+}
+
+#[tokio::test]
+async fn test_image_to_model_with_s3_content() {
+    std::env::set_var("AWS_EC2_METADATA_DISABLED", "true");
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("upload/sts/token"))
+        .and(body_json(json!({ "format": "png" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "sts_ak": "ak",
+                "sts_sk": "sk",
+                "session_token": "token",
+                "resource_bucket": "test-bucket",
+                "resource_uri": "uploads/image.png"
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("test-bucket/uploads/image.png"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(json!({
+            "type": "image_to_model",
+            "file": {
+                "type": "png",
+                "object": { "bucket": "test-bucket", "key": "uploads/image.png" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": "mock_task_id_123" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let image_path = dir.path().join("image.png");
+    fs::write(&image_path, b"\x89PNG\r\n\x1a\n0000").unwrap();
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .s3_endpoint(server.uri())
+        .build()
+        .unwrap();
+
+    let file = client.upload_file_s3(&image_path).await.unwrap();
+    let response = client.image_to_model_with_content(file).await.unwrap();
+    assert_eq!(response.task_id, "mock_task_id_123");
+}
@@ -0,0 +1,38 @@
+use serde_json::json;
+use tripo3d::{TripoClient, TripoError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_validate_success() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 10.0, "frozen": 0.0 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    client.validate().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_validate_rejected_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "code": 1002,
+            "message": "Authentication failed"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::new_with_url(Some("bad_key".to_string()), &server.uri()).unwrap();
+    let err = client.validate().await.unwrap_err();
+    assert!(matches!(err, TripoError::InvalidApiKey));
+}
@@ -0,0 +1,46 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::TripoClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_watch_balance_yields_changes_only() {
+    let server = MockServer::start().await;
+
+    // 1000, 1000, 1000, 980, then 980 forever.
+    for (balance, times) in [(1000.0, 3), (980.0, 1)] {
+        Mock::given(method("GET"))
+            .and(path("user/balance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "balance": balance, "frozen": 0.0 }
+            })))
+            .up_to_n_times(times)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "balance": 980.0, "frozen": 20.0 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+
+    let balances: Vec<_> = client
+        .watch_balance(Duration::from_millis(10))
+        .take(3)
+        .map(|balance| {
+            let balance = balance.unwrap();
+            (balance.balance, balance.frozen)
+        })
+        .collect()
+        .await;
+
+    assert_eq!(balances, vec![(1000.0, 0.0), (980.0, 0.0), (980.0, 20.0)]);
+}
@@ -0,0 +1,78 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{OverflowPolicy, TripoClient, TripoError};
+
+/// Serves `count` updates, then closes the connection and reports that it is done.
+async fn serve_updates(count: usize) -> (String, oneshot::Receiver<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (sent_tx, sent_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        for i in 0..count {
+            let update = json!({
+                "data": {
+                    "task_id": format!("task_{}", i),
+                    "status": "running",
+                    "progress": 50,
+                    "create_time": 1678886400,
+                    "result": {}
+                }
+            });
+            ws.send(Message::Text(update.to_string())).await.unwrap();
+        }
+        ws.close(None).await.unwrap();
+        sent_tx.send(()).unwrap();
+    });
+    (format!("ws://{}/", addr), sent_rx)
+}
+
+async fn collect_slowly(
+    capacity: usize,
+    overflow: OverflowPolicy,
+) -> Vec<Result<String, TripoError>> {
+    let (ws_url, sent) = serve_updates(5).await;
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(ws_url)
+        .watch_buffer(capacity, overflow)
+        .build()
+        .unwrap();
+
+    let updates = client.watch_task("task").await.unwrap();
+    // Let the server send everything and the buffer take it in before consuming
+    // anything. The clock is paused, so the sleep only lets the reader catch up.
+    sent.await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    updates
+        .map(|update| update.map(|status| status.task_id))
+        .collect()
+        .await
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_buffer_drops_oldest() {
+    let task_ids: Vec<_> = collect_slowly(2, OverflowPolicy::DropOldest)
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(task_ids, vec!["task_3", "task_4"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_buffer_overflow_error() {
+    let updates = collect_slowly(2, OverflowPolicy::Error).await;
+    assert_eq!(updates.len(), 3);
+    assert_eq!(updates[0].as_deref().unwrap(), "task_0");
+    assert_eq!(updates[1].as_deref().unwrap(), "task_1");
+    assert!(matches!(
+        updates[2],
+        Err(TripoError::WatchOverflow { capacity: 2 })
+    ));
+}
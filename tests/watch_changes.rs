@@ -0,0 +1,65 @@
+use futures_util::{stream, StreamExt};
+use tripo3d::{watch, TaskResult, TaskState, TaskStatus};
+
+fn update(task_id: &str, status: TaskState, progress: u8) -> TaskStatus {
+    TaskStatus {
+        task_id: task_id.to_string(),
+        task_type: None,
+        prompt: None,
+        status,
+        progress,
+        create_time: 0,
+        result: TaskResult::default(),
+        output: None,
+        consumed_credit: None,
+    }
+}
+
+#[tokio::test]
+async fn test_changes_skips_unchanged_updates() {
+    let updates = stream::iter(vec![
+        Ok(update("a", TaskState::Running, 10)),
+        Ok(update("b", TaskState::Running, 10)),
+        Ok(update("a", TaskState::Running, 10)),
+        Ok(update("a", TaskState::Running, 40)),
+        Ok(update("b", TaskState::Running, 10)),
+        Ok(update("a", TaskState::Success, 100)),
+        Ok(update("b", TaskState::Success, 100)),
+    ]);
+
+    let changes: Vec<_> = watch::changes(updates)
+        .map(|update| {
+            let status = update.unwrap();
+            (status.task_id, status.progress)
+        })
+        .collect()
+        .await;
+
+    assert_eq!(
+        changes,
+        vec![
+            ("a".to_string(), 10),
+            ("b".to_string(), 10),
+            ("a".to_string(), 40),
+            ("a".to_string(), 100),
+            ("b".to_string(), 100),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_changes_stops_after_final_state() {
+    let updates = stream::iter(vec![
+        Ok(update("a", TaskState::Running, 90)),
+        Ok(update("a", TaskState::Success, 100)),
+        Ok(update("a", TaskState::Success, 100)),
+        Ok(update("a", TaskState::Running, 90)),
+    ]);
+
+    let changes: Vec<_> = watch::changes(updates)
+        .map(|update| update.unwrap().status)
+        .collect()
+        .await;
+
+    assert_eq!(changes, vec![TaskState::Running, TaskState::Success]);
+}
@@ -0,0 +1,252 @@
+use flate2::{Compress, Compression, FlushCompress};
+use futures_util::StreamExt;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tripo3d::{TaskState, TaskStatus, TripoClient, TripoError};
+
+const FIN: u8 = 0x80;
+const RSV1: u8 = 0x40;
+const CONTINUATION: u8 = 0x0;
+const TEXT: u8 = 0x1;
+const CLOSE: u8 = 0x8;
+const PING: u8 = 0x9;
+
+/// Compresses messages the way a `permessage-deflate` server does: one raw DEFLATE
+/// stream for the whole connection, each message sync-flushed and stripped of its
+/// trailing `00 00 ff ff`.
+struct Deflater(Compress);
+
+impl Deflater {
+    fn new(level: Compression) -> Self {
+        Deflater(Compress::new(level, false))
+    }
+
+    fn message(&mut self, text: &str) -> Vec<u8> {
+        let mut out = Vec::with_capacity(text.len() * 2 + 64);
+        let start = self.0.total_in();
+        self.0
+            .compress_vec(text.as_bytes(), &mut out, FlushCompress::Sync)
+            .unwrap();
+        assert_eq!(self.0.total_in() - start, text.len() as u64);
+        assert!(out.ends_with(&[0x00, 0x00, 0xff, 0xff]));
+        out.truncate(out.len() - 4);
+        out
+    }
+}
+
+fn update(status: &str, progress: u8, prompt: &str) -> String {
+    json!({
+        "data": {
+            "task_id": "task_123",
+            "status": status,
+            "progress": progress,
+            "create_time": 1678886400,
+            "prompt": prompt,
+            "result": {}
+        }
+    })
+    .to_string()
+}
+
+/// An unmasked server frame.
+fn frame(first_byte: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![first_byte];
+    match payload.len() {
+        len @ 0..=125 => frame.push(len as u8),
+        len @ 126..=0xffff => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// A WebSocket server that answers the handshake with `extensions`, if any, then sends
+/// `frames` followed by a close frame. Returns the URL and the extensions the client
+/// offered.
+async fn serve(extensions: Option<&'static str>, frames: Vec<u8>) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        while !request.ends_with(b"\r\n\r\n") {
+            request.push(stream.read_u8().await.unwrap());
+        }
+        let request = String::from_utf8(request).unwrap();
+        let header = |name: &str| {
+            request
+                .lines()
+                .find_map(|line| {
+                    let (key, value) = line.split_once(':')?;
+                    key.eq_ignore_ascii_case(name)
+                        .then(|| value.trim().to_string())
+                })
+                .unwrap_or_default()
+        };
+        let mut response = format!(
+            "HTTP/1.1 101 Switching Protocols\r\n\
+             Connection: Upgrade\r\n\
+             Upgrade: websocket\r\n\
+             Sec-WebSocket-Accept: {}\r\n",
+            derive_accept_key(header("Sec-WebSocket-Key").as_bytes())
+        );
+        if let Some(extensions) = extensions {
+            response.push_str(&format!("Sec-WebSocket-Extensions: {}\r\n", extensions));
+        }
+        response.push_str("\r\n");
+        stream.write_all(response.as_bytes()).await.unwrap();
+        stream.write_all(&frames).await.unwrap();
+        stream.write_all(&frame(FIN | CLOSE, &[])).await.unwrap();
+
+        // Wait for the client's close frame, or for it to hang up, before closing.
+        let mut reply = [0u8; 6];
+        let _ = stream.read_exact(&mut reply).await;
+        header("Sec-WebSocket-Extensions")
+    });
+    (format!("ws://{}/", addr), server)
+}
+
+async fn watch(ws_url: String) -> Vec<Result<TaskStatus, TripoError>> {
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(ws_url)
+        .build()
+        .unwrap();
+    client.watch_task("task_123").await.unwrap().collect().await
+}
+
+fn states(updates: Vec<Result<TaskStatus, TripoError>>) -> Vec<(TaskState, u8)> {
+    updates
+        .into_iter()
+        .map(|update| {
+            let status = update.unwrap();
+            (status.status, status.progress)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_watch_task_inflates_compressed_frames() {
+    for level in [
+        Compression::none(),
+        Compression::fast(),
+        Compression::best(),
+    ] {
+        let mut deflater = Deflater::new(level);
+        let running = deflater.message(&update("running", 50, "a red car"));
+        // The second message refers back into the first.
+        let success = deflater.message(&update("success", 100, "a red car"));
+        let (head, tail) = success.split_at(success.len() / 2);
+
+        let mut frames = frame(FIN | RSV1 | TEXT, &running);
+        frames.extend(frame(RSV1 | TEXT, head));
+        // Control frames may arrive between the fragments of a message.
+        frames.extend(frame(FIN | PING, b"ping"));
+        frames.extend(frame(FIN | CONTINUATION, tail));
+        let (ws_url, server) = serve(Some("permessage-deflate"), frames).await;
+
+        assert_eq!(
+            states(watch(ws_url).await),
+            vec![(TaskState::Running, 50), (TaskState::Success, 100)]
+        );
+        assert!(server.await.unwrap().starts_with("permessage-deflate"));
+    }
+}
+
+#[tokio::test]
+async fn test_watch_task_keeps_context_across_large_messages() {
+    // Longer than the 32 KiB window, so the second message only compresses well against
+    // what the client kept of the first.
+    let prompt: String = (0..40_000u32)
+        .map(|i| char::from(b'a' + (i.wrapping_mul(2_654_435_761) >> 27) as u8 % 26))
+        .collect();
+    let mut deflater = Deflater::new(Compression::best());
+    let running = deflater.message(&update("running", 50, &prompt));
+    let success = deflater.message(&update("success", 100, &prompt));
+    assert!(success.len() < prompt.len() / 10);
+
+    let mut frames = frame(FIN | RSV1 | TEXT, &running);
+    frames.extend(frame(FIN | RSV1 | TEXT, &success));
+    let (ws_url, _server) = serve(Some("permessage-deflate"), frames).await;
+
+    let updates = watch(ws_url).await;
+    assert_eq!(updates.len(), 2);
+    for update in updates {
+        assert_eq!(update.unwrap().prompt.as_deref(), Some(prompt.as_str()));
+    }
+}
+
+#[tokio::test]
+async fn test_watch_task_starts_a_new_stream_after_a_final_block() {
+    let mut first = Compress::new(Compression::default(), false);
+    let mut running = Vec::with_capacity(256);
+    first
+        .compress_vec(
+            update("running", 50, "").as_bytes(),
+            &mut running,
+            FlushCompress::Finish,
+        )
+        .unwrap();
+    let success = Deflater::new(Compression::default()).message(&update("success", 100, ""));
+
+    let mut frames = frame(FIN | RSV1 | TEXT, &running);
+    frames.extend(frame(FIN | RSV1 | TEXT, &success));
+    let (ws_url, _server) = serve(Some("permessage-deflate"), frames).await;
+
+    assert_eq!(
+        states(watch(ws_url).await),
+        vec![(TaskState::Running, 50), (TaskState::Success, 100)]
+    );
+}
+
+#[tokio::test]
+async fn test_watch_task_passes_uncompressed_frames_through() {
+    let success = Deflater::new(Compression::default()).message(&update("success", 100, ""));
+    let mut frames = frame(FIN | TEXT, update("running", 50, "").as_bytes());
+    frames.extend(frame(FIN | RSV1 | TEXT, &success));
+    let (ws_url, _server) = serve(Some("permessage-deflate"), frames).await;
+
+    assert_eq!(
+        states(watch(ws_url).await),
+        vec![(TaskState::Running, 50), (TaskState::Success, 100)]
+    );
+}
+
+#[tokio::test]
+async fn test_watch_task_without_negotiated_compression() {
+    let frames = frame(FIN | TEXT, update("success", 100, "").as_bytes());
+    let (ws_url, server) = serve(None, frames).await;
+
+    assert_eq!(states(watch(ws_url).await), vec![(TaskState::Success, 100)]);
+    assert!(server.await.unwrap().starts_with("permessage-deflate"));
+}
+
+#[tokio::test]
+async fn test_watch_task_rejects_malformed_compressed_frame() {
+    let frames = frame(FIN | RSV1 | TEXT, &[0xff, 0xff, 0xff, 0xff]);
+    let (ws_url, _server) = serve(Some("permessage-deflate"), frames).await;
+
+    let updates = watch(ws_url).await;
+    assert_eq!(updates.len(), 1);
+    assert!(matches!(updates[0], Err(TripoError::WebSocketError(_))));
+}
+
+#[tokio::test]
+async fn test_watch_task_rejects_oversized_compressed_message() {
+    let bomb = Deflater::new(Compression::best()).message(&" ".repeat(65 << 20));
+    let frames = frame(FIN | RSV1 | TEXT, &bomb);
+    let (ws_url, _server) = serve(Some("permessage-deflate"), frames).await;
+
+    let updates = watch(ws_url).await;
+    assert_eq!(updates.len(), 1);
+    assert!(matches!(updates[0], Err(TripoError::WebSocketError(_))));
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use std::fs;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{TripoClient, WatchCursor};
+
+#[tokio::test]
+async fn test_watch_all_tasks_resumes_from_cursor() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (path_tx, path_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        // The `Err` type is fixed by tungstenite's handshake callback.
+        #[allow(clippy::result_large_err)]
+        let callback = |request: &Request, response: Response| {
+            path_tx.send(request.uri().path().to_string()).unwrap();
+            Ok(response)
+        };
+        let mut ws = tokio_tungstenite::accept_hdr_async(stream, callback)
+            .await
+            .unwrap();
+        let update = json!({
+            "data": {
+                "task_id": "task_1",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        });
+        ws.send(Message::Text(update.to_string())).await.unwrap();
+        ws.close(None).await.unwrap();
+    });
+
+    let dir = tempfile::tempdir().unwrap();
+    let cursor_path = dir.path().join("watch.cursor");
+    fs::write(&cursor_path, "2023-01-01T00:00:00+00:00\n").unwrap();
+    let cursor = WatchCursor::persistent(&cursor_path).unwrap();
+    let resumed_from = cursor.last_seen().unwrap();
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(format!("ws://{}/", addr))
+        .build()
+        .unwrap();
+    let updates: Vec<_> = client
+        .watch_all_tasks_from(&cursor)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(updates.len(), 1);
+
+    assert_eq!(
+        path_rx.await.unwrap(),
+        "/task/watch/all/2023-01-01T00:00:00+00:00"
+    );
+    let last_seen = cursor.last_seen().unwrap();
+    assert!(last_seen > resumed_from);
+    assert_eq!(last_seen, DateTime::from_timestamp(1678886400, 0).unwrap());
+
+    let persisted: DateTime<Utc> = fs::read_to_string(&cursor_path).unwrap().parse().unwrap();
+    assert_eq!(persisted, last_seen);
+    assert_eq!(
+        WatchCursor::persistent(&cursor_path).unwrap().last_seen(),
+        Some(last_seen)
+    );
+}
+
+#[tokio::test]
+async fn test_watch_cursor_never_moves_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let cursor_path = dir.path().join("watch.cursor");
+    let cursor = WatchCursor::persistent(&cursor_path).unwrap();
+    let later = DateTime::from_timestamp(1678886400, 0).unwrap();
+    let earlier = DateTime::from_timestamp(1678800000, 0).unwrap();
+
+    cursor.record(later).await.unwrap();
+    cursor.record(earlier).await.unwrap();
+
+    assert_eq!(cursor.last_seen(), Some(later));
+    let persisted: DateTime<Utc> = fs::read_to_string(&cursor_path).unwrap().parse().unwrap();
+    assert_eq!(persisted, later);
+    assert!(!dir.path().join("watch.cursor.tmp").exists());
+}
@@ -0,0 +1,66 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{TaskState, TripoClient, WatchFilter};
+
+async fn serve_updates(updates: Vec<serde_json::Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        for update in updates {
+            ws.send(Message::Text(json!({ "data": update }).to_string()))
+                .await
+                .unwrap();
+        }
+        ws.close(None).await.unwrap();
+    });
+    format!("ws://{}/", addr)
+}
+
+fn update(task_id: &str, task_type: &str, status: &str) -> serde_json::Value {
+    json!({
+        "task_id": task_id,
+        "type": task_type,
+        "status": status,
+        "progress": 100,
+        "create_time": 1678886400,
+        "result": {}
+    })
+}
+
+#[tokio::test]
+async fn test_watch_all_tasks_filtered() {
+    let ws_url = serve_updates(vec![
+        update("task_1", "text_to_model", "running"),
+        update("task_2", "image_to_model", "success"),
+        update("task_3", "text_to_model", "success"),
+        update("task_4", "text_to_model", "failure"),
+    ])
+    .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(ws_url)
+        .build()
+        .unwrap();
+
+    let filter = WatchFilter::new()
+        .state(TaskState::Success)
+        .state(TaskState::Failure)
+        .task_type("text_to_model");
+    let updates: Vec<_> = client
+        .watch_all_tasks_filtered(None, filter)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    let task_ids: Vec<_> = updates
+        .into_iter()
+        .map(|update| update.unwrap().task_id)
+        .collect();
+    assert_eq!(task_ids, vec!["task_3", "task_4"]);
+}
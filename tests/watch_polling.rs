@@ -0,0 +1,128 @@
+use chrono::DateTime;
+use futures_util::StreamExt;
+use serde_json::json;
+use std::time::Duration;
+use tripo3d::{PollSchedule, TaskState, TripoClient, WatchTransport};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task_body(status: &str, progress: u8) -> serde_json::Value {
+    json!({
+        "data": {
+            "task_id": "mock_task_id_123",
+            "status": status,
+            "progress": progress,
+            "create_time": 1678886400,
+            "result": {}
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_watch_task_over_polling_transport() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_body("running", 40)))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task/mock_task_id_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_body("success", 100)))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .watch_transport(WatchTransport::Polling)
+        .poll_schedule(PollSchedule {
+            running: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let states: Vec<_> = client
+        .watch_task("mock_task_id_123")
+        .await
+        .unwrap()
+        .map(|update| update.unwrap().status)
+        .collect()
+        .await;
+    assert_eq!(states, vec![TaskState::Running, TaskState::Success]);
+}
+
+fn listed_task(task_id: &str, status: &str, progress: u8) -> serde_json::Value {
+    json!({
+        "task_id": task_id,
+        "status": status,
+        "progress": progress,
+        "create_time": 1678886400,
+        "result": {}
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_all_tasks_over_polling_transport() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("task"))
+        .and(query_param("start_time", "1678886400"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "tasks": [listed_task("task_a", "running", 40)],
+                "total": 1
+            }
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("task"))
+        .and(query_param("start_time", "1678886400"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "tasks": [
+                    listed_task("task_b", "running", 10),
+                    listed_task("task_a", "success", 100)
+                ],
+                "total": 2
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .watch_transport(WatchTransport::Polling)
+        .build()
+        .unwrap();
+
+    let since = DateTime::from_timestamp(1678886400, 0).unwrap();
+    let updates: Vec<_> = client
+        .watch_all_tasks(Some(since))
+        .await
+        .unwrap()
+        .take(3)
+        .map(|update| {
+            let status = update.unwrap();
+            (status.task_id, status.status)
+        })
+        .collect()
+        .await;
+    assert_eq!(
+        updates,
+        vec![
+            ("task_a".to_string(), TaskState::Running),
+            ("task_b".to_string(), TaskState::Running),
+            ("task_a".to_string(), TaskState::Success),
+        ]
+    );
+}
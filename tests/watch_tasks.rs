@@ -0,0 +1,138 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{PollSchedule, TaskState, TripoClient, WatchTransport};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task(task_id: &str, status: &str) -> serde_json::Value {
+    json!({
+        "data": {
+            "task_id": task_id,
+            "status": status,
+            "progress": if status == "success" { 100 } else { 50 },
+            "create_time": 1678886400,
+            "result": {}
+        }
+    })
+}
+
+async fn mount_task(server: &MockServer, task_id: &str, statuses: &[&str]) {
+    let (last, first) = statuses.split_last().unwrap();
+    for status in first {
+        Mock::given(method("GET"))
+            .and(path(format!("task/{}", task_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(task(task_id, status)))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(format!("task/{}", task_id)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task(task_id, last)))
+        .mount(server)
+        .await;
+}
+
+fn summarize(mut updates: Vec<(String, TaskState)>) -> Vec<(String, TaskState)> {
+    updates.sort_by(|a, b| a.0.cmp(&b.0));
+    updates
+}
+
+#[tokio::test]
+async fn test_watch_tasks_over_polling_transport() {
+    let server = MockServer::start().await;
+    mount_task(&server, "task_a", &["success"]).await;
+    mount_task(&server, "task_b", &["running", "success"]).await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .watch_transport(WatchTransport::Polling)
+        .poll_schedule(PollSchedule {
+            running: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let updates: Vec<_> = client
+        .watch_tasks(&["task_a", "task_b"])
+        .await
+        .unwrap()
+        .map(|update| {
+            let status = update.unwrap();
+            (status.task_id, status.status)
+        })
+        .collect()
+        .await;
+
+    assert_eq!(
+        summarize(updates),
+        vec![
+            ("task_a".to_string(), TaskState::Success),
+            ("task_b".to_string(), TaskState::Running),
+            ("task_b".to_string(), TaskState::Success),
+        ]
+    );
+    // One request for task_a, two for task_b.
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_watch_tasks_shares_one_websocket() {
+    let server = MockServer::start().await;
+    mount_task(&server, "task_a", &["running"]).await;
+    mount_task(&server, "task_b", &["running"]).await;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        // Give the client time to fetch the current statuses first.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        for (task_id, status) in [
+            ("task_other", "success"),
+            ("task_a", "success"),
+            ("task_b", "success"),
+        ] {
+            let update = task(task_id, status).to_string();
+            ws.send(Message::Text(update)).await.unwrap();
+        }
+        // Keep the connection open; the stream must end on its own.
+        tokio::time::sleep(Duration::from_secs(10)).await;
+    });
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .base_url(server.uri())
+        .ws_url(format!("ws://{}/", addr))
+        .build()
+        .unwrap();
+
+    let updates = client.watch_tasks(&["task_a", "task_b"]).await.unwrap();
+    let updates: Vec<_> = tokio::time::timeout(Duration::from_secs(5), updates.collect())
+        .await
+        .unwrap();
+    let updates: Vec<_> = updates
+        .into_iter()
+        .map(|update| {
+            let status = update.unwrap();
+            (status.task_id, status.status)
+        })
+        .collect();
+
+    assert_eq!(
+        summarize(updates),
+        vec![
+            ("task_a".to_string(), TaskState::Running),
+            ("task_a".to_string(), TaskState::Success),
+            ("task_b".to_string(), TaskState::Running),
+            ("task_b".to_string(), TaskState::Success),
+        ]
+    );
+}
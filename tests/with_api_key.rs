@@ -0,0 +1,37 @@
+use serde_json::json;
+use tripo3d::TripoClient;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_balance(server: &MockServer, api_key: &str, balance: f64) {
+    Mock::given(method("GET"))
+        .and(path("user/balance"))
+        .and(header(
+            "Authorization",
+            format!("Bearer {}", api_key).as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "balance": balance,
+                "frozen": 0.0
+            }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_with_api_key_signs_with_other_key() {
+    let server = MockServer::start().await;
+    mount_balance(&server, "tenant_a_key", 100.0).await;
+    mount_balance(&server, "tenant_b_key", 200.0).await;
+
+    let client =
+        TripoClient::new_with_url(Some("tenant_a_key".to_string()), &server.uri()).unwrap();
+    let tenant_b = client.with_api_key("tenant_b_key");
+
+    assert_eq!(tenant_b.get_balance().await.unwrap().balance, 200.0);
+    // The original client keeps its own key.
+    assert_eq!(client.get_balance().await.unwrap().balance, 100.0);
+}
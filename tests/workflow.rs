@@ -0,0 +1,217 @@
+use serde_json::json;
+use std::fs;
+use tripo3d::{
+    Animation, OutputFormat, TextToModelRequest, TripoClient, TripoError, Workflow, WorkflowState,
+    WorkflowStep,
+};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_task(server: &MockServer, task_id: &str, result: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("task/{task_id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "task_id": task_id,
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": result
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn mount_submission(server: &MockServer, body: serde_json::Value, task_id: &str, n: u64) {
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "task_id": task_id }
+        })))
+        .expect(n)
+        .mount(server)
+        .await;
+}
+
+async fn mount_download(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("files/model.fbx"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"fbx".to_vec()))
+        .mount(server)
+        .await;
+}
+
+fn workflow(dest_dir: &std::path::Path) -> Workflow {
+    Workflow::new(TextToModelRequest::new("a knight"))
+        .target_format(OutputFormat::Fbx)
+        .download(dest_dir)
+}
+
+#[tokio::test]
+async fn test_workflow_runs_every_step() {
+    let server = MockServer::start().await;
+    mount_submission(
+        &server,
+        json!({ "type": "text_to_model", "prompt": "a knight" }),
+        "generate_task",
+        1,
+    )
+    .await;
+    mount_task(&server, "generate_task", json!({})).await;
+    mount_submission(
+        &server,
+        json!({ "type": "animate_rig", "original_model_task_id": "generate_task" }),
+        "rig_task",
+        1,
+    )
+    .await;
+    mount_task(&server, "rig_task", json!({})).await;
+    mount_submission(
+        &server,
+        json!({
+            "type": "animate_retarget",
+            "original_model_task_id": "rig_task",
+            "animation": "preset:walk"
+        }),
+        "animate_task",
+        1,
+    )
+    .await;
+    mount_task(&server, "animate_task", json!({})).await;
+    mount_submission(
+        &server,
+        json!({
+            "type": "convert_model",
+            "original_model_task_id": "animate_task",
+            "format": "FBX"
+        }),
+        "convert_task",
+        1,
+    )
+    .await;
+    mount_task(
+        &server,
+        "convert_task",
+        json!({ "model": { "url": format!("{}/files/model.fbx", server.uri()) } }),
+    )
+    .await;
+    mount_download(&server).await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let state_path = dir.path().join("knight.json");
+
+    let state = workflow(dir.path())
+        .animate(Animation::Walk)
+        .run(&client, &state_path)
+        .await
+        .unwrap();
+    assert!(state.is_finished());
+    assert_eq!(state.task_id(WorkflowStep::Animate), Some("animate_task"));
+    assert_eq!(state.files.len(), 1);
+    assert_eq!(fs::read(&state.files[0]).unwrap(), b"fbx");
+    assert_eq!(WorkflowState::load(&state_path).await.unwrap(), Some(state));
+}
+
+#[tokio::test]
+async fn test_workflow_resumes_without_resubmitting() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("task"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+    mount_task(&server, "generate_task", json!({})).await;
+    mount_task(
+        &server,
+        "convert_task",
+        json!({ "model": { "url": format!("{}/files/model.fbx", server.uri()) } }),
+    )
+    .await;
+    mount_download(&server).await;
+
+    // The process stopped after submitting the conversion.
+    let dir = tempfile::tempdir().unwrap();
+    let state_path = dir.path().join("knight.json");
+    fs::write(
+        &state_path,
+        json!({
+            "fingerprint": workflow(dir.path()).fingerprint(),
+            "steps": [
+                { "step": "generate", "task_id": "generate_task", "done": true },
+                { "step": "convert", "task_id": "convert_task", "done": false },
+                { "step": "download", "done": false }
+            ]
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let state = workflow(dir.path())
+        .run(&client, &state_path)
+        .await
+        .unwrap();
+    assert!(state.is_finished());
+    assert_eq!(state.files.len(), 1);
+}
+
+#[tokio::test]
+async fn test_workflow_rejects_state_of_another_workflow() {
+    let dir = tempfile::tempdir().unwrap();
+    let state_path = dir.path().join("knight.json");
+    let other = Workflow::new(TextToModelRequest::new("a wizard"))
+        .target_format(OutputFormat::Fbx)
+        .download(dir.path());
+    fs::write(
+        &state_path,
+        json!({
+            "fingerprint": other.fingerprint(),
+            "steps": [
+                { "step": "generate", "task_id": "generate_task", "done": true },
+                { "step": "convert", "done": false },
+                { "step": "download", "done": false }
+            ]
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let client = TripoClient::new(Some("test_api_key".to_string())).unwrap();
+    let err = workflow(dir.path())
+        .run(&client, &state_path)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TripoError::ConfigError(_)));
+}
+
+#[tokio::test]
+async fn test_workflow_reports_task_id_when_state_cannot_be_saved() {
+    let server = MockServer::start().await;
+    mount_submission(
+        &server,
+        json!({ "type": "text_to_model", "prompt": "a knight" }),
+        "generate_task",
+        1,
+    )
+    .await;
+
+    let client =
+        TripoClient::new_with_url(Some("test_api_key".to_string()), &server.uri()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let state_path = dir.path().join("missing").join("knight.json");
+
+    let err = workflow(dir.path())
+        .run(&client, &state_path)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TripoError::Unrecorded { ref task_id, .. } if task_id == "generate_task"
+    ));
+}
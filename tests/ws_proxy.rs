@@ -0,0 +1,101 @@
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+use tripo3d::{TripoClient, TripoError};
+
+async fn serve_update() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let update = json!({
+            "data": {
+                "task_id": "task_123",
+                "status": "success",
+                "progress": 100,
+                "create_time": 1678886400,
+                "result": {}
+            }
+        });
+        ws.send(Message::Text(update.to_string())).await.unwrap();
+        ws.close(None).await.unwrap();
+    });
+    format!("ws://{}/", addr)
+}
+
+/// An HTTP proxy that tunnels `CONNECT` requests carrying `authorization`, and records
+/// the request heads it receives.
+async fn connect_proxy(authorization: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let heads = Arc::new(Mutex::new(Vec::new()));
+    let recorded = heads.clone();
+    tokio::spawn(async move {
+        let (mut client, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(client.read_u8().await.unwrap());
+        }
+        let head = String::from_utf8(head).unwrap();
+        recorded.lock().unwrap().push(head.clone());
+
+        let expected = format!("proxy-authorization: basic {}", authorization);
+        if !head.to_lowercase().contains(&expected.to_lowercase()) {
+            client
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+            return;
+        }
+        let target = head.split_whitespace().nth(1).unwrap().to_string();
+        let mut upstream = TcpStream::connect(target).await.unwrap();
+        client
+            .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+            .await
+            .unwrap();
+        let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+    });
+    (format!("127.0.0.1:{}", addr.port()), heads)
+}
+
+#[tokio::test]
+async fn test_watch_task_through_authenticated_proxy() {
+    // base64("user:p@ss")
+    let (proxy, heads) = connect_proxy("dXNlcjpwQHNz").await;
+    let ws_url = serve_update().await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(&ws_url)
+        .proxy(format!("http://user:p%40ss@{}", proxy))
+        .build()
+        .unwrap();
+
+    let updates: Vec<_> = client.watch_task("task_123").await.unwrap().collect().await;
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].as_ref().unwrap().task_id, "task_123");
+
+    let heads = heads.lock().unwrap();
+    let target = ws_url.trim_start_matches("ws://").trim_end_matches('/');
+    assert!(heads[0].starts_with(&format!("CONNECT {} HTTP/1.1", target)));
+}
+
+#[tokio::test]
+async fn test_watch_task_proxy_rejects_credentials() {
+    let (proxy, _) = connect_proxy("dXNlcjpwQHNz").await;
+    let ws_url = serve_update().await;
+
+    let client = TripoClient::builder()
+        .api_key("test_api_key")
+        .ws_url(ws_url)
+        .proxy(format!("http://user:wrong@{}", proxy))
+        .build()
+        .unwrap();
+
+    let result = client.watch_task("task_123").await;
+    assert!(matches!(result, Err(TripoError::ProxyError(message)) if message.contains("407")));
+}